@@ -0,0 +1,135 @@
+/// Information about the test case which is currently running.
+///
+/// Within the body of a parameterized test function, the information about the running test case
+/// can be obtained with the [`case!`] macro.
+///
+/// **Example**
+///
+/// ```rust
+/// use yare::parameterized;
+///
+/// #[parameterized(
+///     small = { 10 },
+///     large = { 10_000 },
+/// )]
+/// fn shuffle(len: usize) {
+///     let info = yare::case!();
+///
+///     assert!(info.name == "small" || info.name == "large");
+///     assert_eq!(info.test_fn, "shuffle");
+///     // A deterministic seed, which is different for each test case
+///     let _seed = info.seed();
+/// }
+/// ```
+///
+/// [`case!`]: crate::case
+#[derive(Debug)]
+pub struct CaseInfo {
+    /// The id of the test case, e.g. `zero_plus_five`
+    pub name: &'static str,
+    /// The position of the test case in the list of test cases of the test function, starting at 0
+    pub index: usize,
+    /// The name of the parameterized test function, e.g. `test_add5`
+    pub test_fn: &'static str,
+    /// The path of the module which contains the test case, as given by `module_path!()`
+    pub module_path: &'static str,
+    /// The name of each parameter, with the source of its argument
+    pub args: &'static [(&'static str, &'static str)],
+    /// The file in which the test case is defined
+    pub file: &'static str,
+    /// The line on which the test case is defined
+    pub line: u32,
+    /// The column on which the test case is defined
+    pub column: u32,
+}
+
+impl CaseInfo {
+    /// The fully qualified name of the test case, e.g. `my_crate::tests::test_add5::zero_plus_five`.
+    pub fn qualified_name(&self) -> String {
+        format!("{}::{}", self.module_path, self.name)
+    }
+
+    /// A seed which is derived from the fully qualified name of the test case.
+    ///
+    /// The seed is stable: it only changes when the test case, its test function or one of its
+    /// enclosing modules is renamed.
+    pub const fn seed(&self) -> u64 {
+        // 64-bit FNV-1a
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+        let hash = fnv1a(OFFSET_BASIS, self.module_path.as_bytes());
+        let hash = fnv1a(hash, b"::");
+
+        fnv1a(hash, self.name.as_bytes())
+    }
+}
+
+const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(PRIME);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Returns the [`CaseInfo`] of the test case which is currently running, as a `&'static CaseInfo`.
+///
+/// This macro can only be used within the body of a parameterized test function.
+#[macro_export]
+macro_rules! case {
+    () => {
+        &__YARE_CASE
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CaseInfo;
+
+    fn case_info(module_path: &'static str, name: &'static str) -> CaseInfo {
+        CaseInfo {
+            name,
+            index: 0,
+            test_fn: "test",
+            module_path,
+            args: &[],
+            file: "src/lib.rs",
+            line: 1,
+            column: 1,
+        }
+    }
+
+    #[test]
+    fn qualified_name() {
+        assert_eq!(
+            case_info("my_crate::test", "one").qualified_name(),
+            "my_crate::test::one"
+        );
+    }
+
+    #[test]
+    fn seed_is_stable() {
+        assert_eq!(
+            case_info("my_crate::test", "one").seed(),
+            case_info("my_crate::test", "one").seed()
+        );
+    }
+
+    #[test]
+    fn seed_differs_per_test_case() {
+        assert_ne!(
+            case_info("my_crate::test", "one").seed(),
+            case_info("my_crate::test", "two").seed()
+        );
+        assert_ne!(
+            case_info("my_crate::test", "one").seed(),
+            case_info("my_crate::other", "one").seed()
+        );
+    }
+}
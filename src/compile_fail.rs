@@ -0,0 +1,193 @@
+use crate::CaseInfo;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The directory in the target directory, in which the source of each test case is written while
+/// it's being compiled.
+const SCRATCH_DIR: &str = "yare";
+
+/// Checks with trybuild that the source of a `#[parameterized(compile_fail, ...)]` test case fails
+/// to compile, with the expected compiler output.
+///
+/// Both the source and the expected output are either given inline, or as a path relative to the
+/// manifest directory of the crate, e.g. `"tests/fail/missing_bound.rs"`. The source is copied to
+/// a scratch file in the target directory named after the test case, together with the expected
+/// output, since trybuild expects the output next to the source. The scratch files are removed
+/// when the test case finishes. With `TRYBUILD=overwrite`, an expected output which is given as a
+/// path is overwritten with the actual output.
+#[track_caller]
+pub fn compile_fail(case: &CaseInfo, source: &str, expected: &str) {
+    let manifest_dir = match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => panic!(
+            "yare: compile_fail test case `{}::{}` must be run by cargo, since CARGO_MANIFEST_DIR isn't set",
+            case.test_fn, case.name
+        ),
+    };
+
+    let source_file = file(&manifest_dir, source, "rs");
+    let expected_file = file(&manifest_dir, expected, "stderr");
+
+    let scratch = ScratchFiles::of(case);
+
+    let written = fs::create_dir_all(&scratch.dir)
+        .and_then(|_| fs::write(&scratch.source, read(case, source_file.as_deref(), source)))
+        .and_then(|_| {
+            // An inline expected output may start on the line after the opening quote
+            fs::write(
+                &scratch.expected,
+                read(
+                    case,
+                    expected_file.as_deref(),
+                    expected.trim_start_matches('\n'),
+                ),
+            )
+        });
+
+    if let Err(error) = written {
+        panic!(
+            "yare: unable to write the source of compile_fail test case `{}::{}` to {}: {}",
+            case.test_fn,
+            case.name,
+            scratch.source.display(),
+            error
+        );
+    }
+
+    // Runs when dropped, and panics when the source compiles, or when the output doesn't match
+    trybuild::TestCases::new().compile_fail(&scratch.source);
+
+    if let Some(expected_file) = expected_file {
+        if std::env::var_os("TRYBUILD").map_or(false, |mode| mode == "overwrite") {
+            if let Err(error) = fs::copy(&scratch.expected, &expected_file) {
+                panic!(
+                    "yare: unable to overwrite {} for compile_fail test case `{}::{}`: {}",
+                    expected_file.display(),
+                    case.test_fn,
+                    case.name,
+                    error
+                );
+            }
+        }
+    }
+}
+
+/// The file an argument refers to, when it's a single line ending with the given extension.
+fn file(manifest_dir: &Path, argument: &str, extension: &str) -> Option<PathBuf> {
+    let path = Path::new(argument);
+
+    if argument.contains('\n') || path.extension().map_or(true, |ext| ext != extension) {
+        return None;
+    }
+
+    Some(manifest_dir.join(path))
+}
+
+#[track_caller]
+fn read(case: &CaseInfo, file: Option<&Path>, argument: &str) -> String {
+    match file {
+        Some(file) => fs::read_to_string(file).unwrap_or_else(|error| {
+            panic!(
+                "yare: unable to read {} for compile_fail test case `{}::{}`: {}",
+                file.display(),
+                case.test_fn,
+                case.name,
+                error
+            )
+        }),
+        None => argument.to_string(),
+    }
+}
+
+/// The scratch files of a test case, which are removed when dropped, also when the test case
+/// fails.
+struct ScratchFiles {
+    dir: PathBuf,
+    source: PathBuf,
+    expected: PathBuf,
+}
+
+impl ScratchFiles {
+    fn of(case: &CaseInfo) -> ScratchFiles {
+        let source = target_dir().join(scratch_file(case));
+        let expected = source.with_extension("stderr");
+        let dir = source.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        ScratchFiles {
+            dir,
+            source,
+            expected,
+        }
+    }
+}
+
+impl Drop for ScratchFiles {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.source);
+        let _ = fs::remove_file(&self.expected);
+
+        // Removing a directory fails when other test cases still use it
+        for dir in self.dir.ancestors() {
+            if dir.ends_with(SCRATCH_DIR) || fs::remove_dir(dir).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// The target directory of the test binary, e.g. `target/debug` for `target/debug/deps/my_crate-…`.
+fn target_dir() -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.parent()?.parent()?.to_path_buf()))
+        .unwrap_or_else(std::env::temp_dir)
+}
+
+/// The scratch file for the source of a test case, relative to the target directory, e.g.
+/// `yare/my_crate/tests/missing_bound/generic.rs` for `my_crate::tests::missing_bound::generic`.
+fn scratch_file(case: &CaseInfo) -> PathBuf {
+    case.module_path
+        .split("::")
+        .fold(PathBuf::from(SCRATCH_DIR), |dir, segment| dir.join(segment))
+        .join(case.name)
+        .with_extension("rs")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{file, scratch_file};
+    use crate::CaseInfo;
+    use std::path::Path;
+
+    #[test]
+    fn arguments_which_are_files() {
+        let dir = Path::new("/crate");
+
+        assert_eq!(
+            file(dir, "tests/fail/bound.rs", "rs"),
+            Some(dir.join("tests/fail/bound.rs"))
+        );
+        assert_eq!(file(dir, "tests/fail/bound.rs", "stderr"), None);
+        assert_eq!(file(dir, "fn main() {}", "rs"), None);
+        assert_eq!(file(dir, "// a.rs\nfn main() {}\n// b.rs", "rs"), None);
+    }
+
+    #[test]
+    fn scratch_file_per_test_case() {
+        static CASE: CaseInfo = CaseInfo {
+            name: "generic",
+            index: 0,
+            test_fn: "missing_bound",
+            module_path: "my_crate::tests::missing_bound",
+            args: &[],
+            file: "src/tests.rs",
+            line: 1,
+            column: 1,
+        };
+
+        assert_eq!(
+            scratch_file(&CASE),
+            Path::new("yare/my_crate/tests/missing_bound/generic.rs")
+        );
+    }
+}
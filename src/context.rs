@@ -0,0 +1,113 @@
+use crate::CaseInfo;
+use std::cell::Cell;
+
+/// Prints the context of a test case to stderr, when the test case panics while the guard is alive.
+///
+/// The guard tracks which argument is being evaluated, so a panic while evaluating an argument can
+/// be distinguished from a panic in the body of the test function.
+pub struct CaseGuard {
+    case: &'static CaseInfo,
+    evaluating: Cell<Option<&'static str>>,
+}
+
+impl CaseGuard {
+    pub fn new(case: &'static CaseInfo) -> Self {
+        CaseGuard {
+            case,
+            evaluating: Cell::new(None),
+        }
+    }
+
+    /// Marks the start of the evaluation of the argument for the given parameter.
+    pub fn evaluating(&self, parameter: &'static str) {
+        self.evaluating.set(Some(parameter));
+    }
+
+    /// Marks the end of the evaluation of the arguments, and the start of the test body.
+    pub fn running(&self) {
+        self.evaluating.set(None);
+    }
+}
+
+impl Drop for CaseGuard {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            eprintln!("{}", Report(self));
+        }
+    }
+}
+
+struct Report<'guard>(&'guard CaseGuard);
+
+impl std::fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let case = self.0.case;
+
+        match self.0.evaluating.get() {
+            Some(parameter) => writeln!(
+                f,
+                "yare: argument evaluation failed for `{}` in test case `{}::{}`",
+                parameter, case.test_fn, case.name
+            )?,
+            None => writeln!(
+                f,
+                "yare: test case `{}::{}` failed",
+                case.test_fn, case.name
+            )?,
+        }
+
+        writeln!(
+            f,
+            "  defined at: {}:{}:{}",
+            case.file, case.line, case.column
+        )?;
+
+        if !case.args.is_empty() {
+            writeln!(f, "  arguments:")?;
+        }
+
+        for (parameter, argument) in case.args {
+            writeln!(f, "    {} = {}", parameter, argument)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CaseGuard, Report};
+    use crate::CaseInfo;
+
+    static CASE: CaseInfo = CaseInfo {
+        name: "two",
+        index: 1,
+        test_fn: "double",
+        module_path: "my_crate::double",
+        args: &[("input", "1 + 1"), ("expected", "4")],
+        file: "src/double.rs",
+        line: 4,
+        column: 5,
+    };
+
+    #[test]
+    fn reports_failed_test_case() {
+        let guard = CaseGuard::new(&CASE);
+        guard.running();
+
+        assert_eq!(
+            Report(&guard).to_string(),
+            "yare: test case `double::two` failed\n  defined at: src/double.rs:4:5\n  arguments:\n    input = 1 + 1\n    expected = 4\n"
+        );
+    }
+
+    #[test]
+    fn reports_failed_argument_evaluation() {
+        let guard = CaseGuard::new(&CASE);
+        guard.evaluating("expected");
+
+        assert!(Report(&guard).to_string().starts_with(
+            "yare: argument evaluation failed for `expected` in test case `double::two`\n"
+        ));
+    }
+}
@@ -0,0 +1,53 @@
+use crate::CaseInfo;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// Parses the argument of a `#[from_str]` parameter, e.g. `"1.2.3"` for a `Version`.
+#[track_caller]
+pub fn from_str<T, S>(case: &CaseInfo, parameter: &str, argument: S) -> T
+where
+    T: FromStr,
+    T::Err: Debug,
+    S: AsRef<str>,
+{
+    let argument = argument.as_ref();
+
+    match argument.parse() {
+        Ok(value) => value,
+        Err(error) => panic!(
+            "yare: unable to parse {:?} for parameter `{}` in test case `{}::{}`: {:?}",
+            argument, parameter, case.test_fn, case.name, error
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_str;
+    use crate::CaseInfo;
+
+    static CASE: CaseInfo = CaseInfo {
+        name: "invalid",
+        index: 0,
+        test_fn: "parse",
+        module_path: "yare::conversion::tests",
+        args: &[("number", "\"x\"")],
+        file: "src/conversion.rs",
+        line: 1,
+        column: 1,
+    };
+
+    #[test]
+    fn parses() {
+        assert_eq!(from_str::<u32, _>(&CASE, "number", "42"), 42);
+        assert_eq!(from_str::<u32, _>(&CASE, "number", String::from("7")), 7);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "yare: unable to parse \"x\" for parameter `number` in test case `parse::invalid`"
+    )]
+    fn names_case_and_parameter() {
+        from_str::<u32, _>(&CASE, "number", "x");
+    }
+}
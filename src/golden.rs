@@ -0,0 +1,407 @@
+use crate::CaseInfo;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The golden file of a test case of a `#[parameterized(golden = "...")]` test function, which
+/// holds the expected output of the test case.
+///
+/// Within the body of the test function, the golden file of the running test case can be obtained
+/// with the [`golden!`] macro.
+///
+/// **Example**
+///
+/// ```rust
+/// use yare::parameterized;
+///
+/// #[parameterized(
+///     golden = "tests/golden/{fn}/{case}.txt",
+///     empty = { "" },
+///     words = { "yet another rust example" },
+/// )]
+/// fn title_case(input: &str) {
+///     let actual = input
+///         .split(' ')
+///         .filter(|word| !word.is_empty())
+///         .map(|word| word[..1].to_uppercase() + &word[1..])
+///         .collect::<Vec<_>>()
+///         .join(" ");
+///
+///     yare::golden!().assert_eq(&actual);
+/// }
+/// ```
+///
+/// [`golden!`]: crate::golden
+#[derive(Debug)]
+pub struct Golden {
+    case: &'static CaseInfo,
+    manifest_dir: &'static str,
+    pattern: &'static str,
+    cases: &'static [&'static str],
+}
+
+impl Golden {
+    #[doc(hidden)]
+    pub const fn __new(
+        case: &'static CaseInfo,
+        manifest_dir: &'static str,
+        pattern: &'static str,
+        cases: &'static [&'static str],
+    ) -> Self {
+        Golden {
+            case,
+            manifest_dir,
+            pattern,
+            cases,
+        }
+    }
+
+    /// The path of the golden file, relative to the manifest directory of the crate, e.g.
+    /// `tests/golden/format/empty.txt`.
+    pub fn path(&self) -> PathBuf {
+        PathBuf::from(self.pattern.replace("{case}", self.case.name))
+    }
+
+    /// Asserts that the actual output of the test case equals the contents of its golden file, and
+    /// shows a unified diff when it doesn't.
+    ///
+    /// When the `YARE_UPDATE` environment variable is set to `1`, the golden file is written with
+    /// the actual output instead, and created when it doesn't exist yet. Golden files which aren't
+    /// used by any test case of the test function are then reported as a warning by its first test
+    /// case, so they're not reported when a test filter skips that test case.
+    #[track_caller]
+    pub fn assert_eq(&self, actual: &str) {
+        let update = std::env::var_os("YARE_UPDATE").map_or(false, |value| value == "1");
+
+        self.check(actual, update);
+    }
+
+    #[track_caller]
+    fn check(&self, actual: &str, update: bool) {
+        let path = self.path();
+        let file = Path::new(self.manifest_dir).join(&path);
+
+        if update {
+            let written = fs::create_dir_all(file.parent().unwrap_or_else(|| Path::new("")))
+                .and_then(|_| fs::write(&file, actual));
+
+            if let Err(error) = written {
+                panic!(
+                    "yare: unable to update golden file {} of test case `{}::{}`: {}",
+                    path.display(),
+                    self.case.test_fn,
+                    self.case.name,
+                    error
+                );
+            }
+
+            if self.cases.first() == Some(&self.case.name) {
+                let unused = self.unused_files();
+
+                if !unused.is_empty() {
+                    eprintln!(
+                        "yare: warning: updated the golden files of `{}`, but these golden files aren't used by any of its test cases:\n{}",
+                        self.case.test_fn,
+                        unused
+                            .iter()
+                            .map(|file| format!("  {}", file.display()))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    );
+                }
+            }
+
+            return;
+        }
+
+        let expected = match fs::read_to_string(&file) {
+            Ok(expected) => expected,
+            Err(error) => panic!(
+                "yare: unable to read golden file {} of test case `{}::{}`: {}; run with YARE_UPDATE=1 to create it",
+                path.display(),
+                self.case.test_fn,
+                self.case.name,
+                error
+            ),
+        };
+
+        if expected != actual {
+            panic!(
+                "yare: the output of test case `{}::{}` doesn't match golden file {}; run with YARE_UPDATE=1 to update it\n{}",
+                self.case.test_fn,
+                self.case.name,
+                path.display(),
+                unified_diff(&path.display().to_string(), &expected, actual)
+            );
+        }
+    }
+
+    /// The golden files of test cases which no longer exist, i.e. the files next to the golden
+    /// files of the test cases, which match the pattern, but not one of the test cases.
+    fn unused_files(&self) -> Vec<PathBuf> {
+        let pattern = Path::new(self.pattern);
+
+        let (dir, file_name) = match (pattern.parent(), pattern.file_name()) {
+            (Some(dir), Some(file_name)) => (dir, file_name.to_string_lossy()),
+            _ => return Vec::new(),
+        };
+
+        // Only a `{case}` in the file name can be matched
+        let (prefix, suffix) = match file_name.split_once("{case}") {
+            Some((prefix, suffix)) if !dir.to_string_lossy().contains("{case}") => (prefix, suffix),
+            _ => return Vec::new(),
+        };
+
+        let entries = match fs::read_dir(Path::new(self.manifest_dir).join(dir)) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut unused = entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|name| {
+                name.len() > prefix.len() + suffix.len()
+                    && name.starts_with(prefix)
+                    && name.ends_with(suffix)
+                    && !self
+                        .cases
+                        .contains(&&name[prefix.len()..name.len() - suffix.len()])
+            })
+            .map(|name| dir.join(name))
+            .collect::<Vec<_>>();
+
+        unused.sort();
+        unused
+    }
+}
+
+/// Returns the [`Golden`] file of the test case which is currently running, as a
+/// `&'static Golden`.
+///
+/// This macro can only be used within the body of a test function with golden files, e.g.
+/// `#[parameterized(golden = "tests/golden/{fn}/{case}.txt", ...)]`.
+#[macro_export]
+macro_rules! golden {
+    () => {
+        &__YARE_GOLDEN
+    };
+}
+
+/// The number of unchanged lines which are shown around each change.
+const CONTEXT: usize = 3;
+
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// A unified diff of the lines of the expected and the actual output.
+fn unified_diff(name: &str, expected: &str, actual: &str) -> String {
+    let old = expected.lines().collect::<Vec<_>>();
+    let new = actual.lines().collect::<Vec<_>>();
+
+    // The length of the longest common subsequence of the remaining lines
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+
+    let mut diff = format!("--- {}\n+++ actual\n", name);
+
+    let changes = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Line::Same(_)))
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    // Changes which are close to each other share a hunk
+    let mut hunks = Vec::<(usize, usize)>::new();
+
+    for &index in &changes {
+        let start = index.saturating_sub(CONTEXT);
+        let end = (index + CONTEXT + 1).min(lines.len());
+
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    for (start, end) in hunks {
+        let (old_start, new_start) =
+            lines[..start]
+                .iter()
+                .fold((0, 0), |(old, new), line| match line {
+                    Line::Same(_) => (old + 1, new + 1),
+                    Line::Removed(_) => (old + 1, new),
+                    Line::Added(_) => (old, new + 1),
+                });
+
+        let hunk = &lines[start..end];
+        let old_count = hunk
+            .iter()
+            .filter(|line| !matches!(line, Line::Added(_)))
+            .count();
+        let new_count = hunk
+            .iter()
+            .filter(|line| !matches!(line, Line::Removed(_)))
+            .count();
+
+        // An empty range starts at the line before it
+        let _ = writeln!(
+            diff,
+            "@@ -{},{} +{},{} @@",
+            old_start + usize::from(old_count > 0),
+            old_count,
+            new_start + usize::from(new_count > 0),
+            new_count
+        );
+
+        for line in hunk {
+            let _ = match line {
+                Line::Same(line) => writeln!(diff, " {}", line),
+                Line::Removed(line) => writeln!(diff, "-{}", line),
+                Line::Added(line) => writeln!(diff, "+{}", line),
+            };
+        }
+    }
+
+    if changes.is_empty() {
+        diff.push_str("(only the line endings or the newline at the end differ)\n");
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{unified_diff, Golden};
+    use crate::CaseInfo;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    fn case(name: &'static str) -> &'static CaseInfo {
+        Box::leak(Box::new(CaseInfo {
+            name,
+            index: 0,
+            test_fn: "format",
+            module_path: "yare::golden::tests::format",
+            args: &[],
+            file: "src/golden.rs",
+            line: 1,
+            column: 1,
+        }))
+    }
+
+    /// A directory of its own for each test, which is removed when the test passes.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("yare-golden-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+
+            TempDir(dir)
+        }
+
+        fn golden(&self, name: &'static str, cases: &'static [&'static str]) -> Golden {
+            let dir = Box::leak(self.0.to_string_lossy().into_owned().into_boxed_str());
+
+            Golden::__new(case(name), dir, "golden/format/{case}.txt", cases)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn diff_shows_changes_with_context() {
+        let expected = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let actual = "a\nb\nC\nd\ne\nf\ng\nh\ni\nj\nk\n";
+
+        assert_eq!(
+            unified_diff("expected.txt", expected, actual),
+            "--- expected.txt\n+++ actual\n\
+             @@ -1,6 +1,6 @@\n a\n b\n-c\n+C\n d\n e\n f\n\
+             @@ -8,3 +8,4 @@\n h\n i\n j\n+k\n"
+        );
+    }
+
+    #[test]
+    fn diff_of_empty_output() {
+        assert_eq!(
+            unified_diff("expected.txt", "", "a\n"),
+            "--- expected.txt\n+++ actual\n@@ -0,0 +1,1 @@\n+a\n"
+        );
+    }
+
+    #[test]
+    fn update_creates_and_checks() {
+        let dir = TempDir::new("update");
+        let golden = dir.golden("one", &["one"]);
+
+        golden.check("output\n", true);
+
+        assert_eq!(
+            fs::read_to_string(dir.0.join("golden/format/one.txt")).unwrap(),
+            "output\n"
+        );
+
+        golden.check("output\n", false);
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't match golden file golden/format/one.txt")]
+    fn mismatch() {
+        let dir = TempDir::new("mismatch");
+        let golden = dir.golden("one", &["one"]);
+
+        golden.check("output\n", true);
+        golden.check("other output\n", false);
+    }
+
+    #[test]
+    fn update_finds_unused_files() {
+        let dir = TempDir::new("unused");
+        let removed = dir.golden("removed", &["removed"]);
+        let one = dir.golden("one", &["one", "two"]);
+
+        removed.check("output\n", true);
+        one.check("output\n", true);
+
+        assert_eq!(
+            one.unused_files(),
+            vec![Path::new("golden/format/removed.txt")]
+        );
+    }
+}
@@ -1,9 +1,64 @@
 #![doc = include_str!("../README.md")]
 #![deny(clippy::all)]
+// The README marks the test functions of a parameterized module with #[test]
+#![allow(clippy::test_attr_in_doctest)]
 
-extern crate yare_macro as yare;
+// Allows generated code to refer to `::yare` from within this crate as well
+extern crate self as yare;
 
-pub use yare::parameterized;
+pub use yare_macro::{
+    cases, parameterized, parameterized_bench, parameterized_types, Spread, Variants,
+};
+
+#[doc(hidden)]
+pub use yare_macro::__resolve_cases;
+
+pub use case_info::CaseInfo;
+pub use golden::Golden;
+pub use variants::Variants;
+
+mod case_info;
+mod context;
+mod conversion;
+mod golden;
+mod spread;
+mod variants;
+
+// The runner is optional, so it may require a newer Rust version than the rest of the crate
+#[cfg(feature = "runner")]
+#[clippy::msrv = "1.59"]
+pub mod runner;
+
+// Strategies for property-based test cases, e.g. `yare::proptest::prelude::any::<u8>()`
+#[cfg(feature = "proptest")]
+pub use proptest;
+
+#[cfg(feature = "proptest")]
+mod property;
+
+// Benchmarks of test cases, e.g. `criterion_main!(my_bench::benches)`
+#[cfg(feature = "criterion")]
+pub use criterion;
+
+// Compile-fail test cases are checked by trybuild, which may require a newer Rust version
+#[cfg(feature = "trybuild")]
+mod compile_fail;
+
+/// Items used by the generated code, which are not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::context::CaseGuard;
+    pub use crate::conversion::from_str;
+    pub use crate::spread::{spread, SpreadOverParameters};
+
+    #[cfg(feature = "trybuild")]
+    pub use crate::compile_fail::compile_fail;
+
+    #[cfg(feature = "proptest")]
+    pub mod proptest {
+        pub use crate::property::{run, Outcome};
+    }
+}
 
 #[cfg(test)]
 mod tests;
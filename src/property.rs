@@ -0,0 +1,114 @@
+use crate::CaseInfo;
+use proptest::strategy::Strategy;
+use proptest::test_runner::{Config, TestCaseError, TestCaseResult, TestError, TestRunner};
+
+/// Runs a property-based test case, which tests the property with values generated by the strategy.
+///
+/// When the property fails, the input is shrunk to a minimal failing input, which is reported as a
+/// test case which can be pasted into the `#[parameterized(...)]` attribute, to keep it as a
+/// regression example. The arguments are described by `describe`, which the macro generates from
+/// the types of the parameters.
+#[track_caller]
+pub fn run<S, D, T>(case: &'static CaseInfo, strategy: S, describe: D, test: T)
+where
+    S: Strategy,
+    D: Fn(&S::Value) -> Vec<String>,
+    T: Fn(S::Value) -> TestCaseResult,
+{
+    // Failures are reported as test cases, so they don't have to be persisted by proptest
+    let config = Config {
+        failure_persistence: None,
+        ..Config::default()
+    };
+
+    match TestRunner::new(config).run(&strategy, test) {
+        Ok(()) => {}
+        Err(TestError::Abort(reason)) => panic!(
+            "yare: property-based test case `{}::{}` was aborted: {}",
+            case.test_fn, case.name, reason
+        ),
+        Err(TestError::Fail(reason, value)) => panic!(
+            "yare: property-based test case `{}::{}` failed: {}\n  minimal failing input, as a test case:\n    {}",
+            case.test_fn,
+            case.name,
+            reason,
+            minimal_case(case.name, &describe(&value))
+        ),
+    }
+}
+
+/// A test case for the minimal failing input, e.g. `generated_minimal = { 0, "".into() },`.
+fn minimal_case(name: &str, arguments: &[String]) -> String {
+    format!("{}_minimal = {{ {} }},", name, arguments.join(", "))
+}
+
+/// The outcome of a property, i.e. the return type of a test function with strategies.
+pub trait Outcome {
+    fn into_test_case_result(self) -> TestCaseResult;
+}
+
+impl Outcome for () {
+    fn into_test_case_result(self) -> TestCaseResult {
+        Ok(())
+    }
+}
+
+impl<E: std::fmt::Debug> Outcome for Result<(), E> {
+    fn into_test_case_result(self) -> TestCaseResult {
+        self.map_err(|error| TestCaseError::fail(format!("{:?}", error)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{minimal_case, run};
+    use crate::CaseInfo;
+
+    const CASE: CaseInfo = CaseInfo {
+        name: "generated",
+        index: 0,
+        test_fn: "halves",
+        module_path: "yare::property::tests",
+        args: &[("n", "0..1000u32")],
+        file: file!(),
+        line: line!(),
+        column: column!(),
+    };
+
+    #[test]
+    fn minimal_case_syntax() {
+        let arguments = [format!("{:?}", 3), format!("{:?}", "abc")];
+
+        assert_eq!(
+            minimal_case("generated", &arguments),
+            r#"generated_minimal = { 3, "abc" },"#
+        );
+    }
+
+    #[test]
+    fn passing_property() {
+        run(
+            &CASE,
+            (0..1000u32,),
+            |(n,)| vec![format!("{:?}", n)],
+            |(n,)| {
+                assert!(n / 2 <= n);
+                Ok(())
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "generated_minimal = { 10 },")]
+    fn failing_property_is_shrunk() {
+        run(
+            &CASE,
+            (0..1000u32,),
+            |(n,)| vec![format!("{:?}", n)],
+            |(n,)| {
+                assert!(n < 10);
+                Ok(())
+            },
+        );
+    }
+}
@@ -0,0 +1,708 @@
+//! A test runner for test targets with `harness = false`, which can run test cases which are only
+//! known at runtime, next to the test cases of `#[parameterized(runner, ...)]` test functions.
+//!
+//! The runner accepts the most common command line arguments of the default test harness: name
+//! filters, `--exact`, `--skip`, `--ignored`, `--include-ignored`, `--list`, `--format terse` and
+//! `--test-threads`. Tests run in parallel, each on its own thread. The output of tests isn't
+//! captured, so `--nocapture` and `--show-output` are rejected.
+//!
+//! The runner requires Rust 1.59 or newer.
+//!
+//! **Example**
+//!
+//! ```toml
+//! # Cargo.toml
+//! [[test]]
+//! name = "corpus"
+//! harness = false
+//! ```
+//!
+//! ```rust,no_run
+//! // tests/corpus.rs
+//! use yare::parameterized;
+//! use yare::runner::Runner;
+//!
+//! #[parameterized(
+//!     runner,
+//!     empty = { "" },
+//!     ascii = { "yare" },
+//! )]
+//! fn is_utf8(input: &str) {
+//!     assert!(std::str::from_utf8(input.as_bytes()).is_ok());
+//! }
+//!
+//! fn main() {
+//!     let corpus = std::env::var("CORPUS").unwrap_or_default();
+//!     let rows = corpus.lines().map(|row| (row.to_string(), row.to_string()));
+//!
+//!     Runner::from_args()
+//!         .trials(is_utf8::trials())
+//!         .cases("corpus", rows, |row: String| assert!(!row.is_empty()))
+//!         .run();
+//! }
+//! ```
+
+use std::any::Any;
+use std::io::Write;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Collects the tests of a test target, and runs them according to the command line arguments.
+pub struct Runner {
+    options: Options,
+    trials: Vec<Trial>,
+}
+
+impl Runner {
+    /// A runner, configured by the command line arguments of the process.
+    ///
+    /// Exits the process when the arguments are invalid, or when `--help` was given.
+    pub fn from_args() -> Self {
+        match Options::parse(std::env::args().skip(1)) {
+            Ok(Some(options)) => Runner {
+                options,
+                trials: Vec::new(),
+            },
+            Ok(None) => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            Err(message) => {
+                eprintln!("error: {}", message);
+                std::process::exit(101);
+            }
+        }
+    }
+
+    /// Adds a single test.
+    pub fn trial(mut self, trial: Trial) -> Self {
+        self.trials.push(trial);
+        self
+    }
+
+    /// Adds tests, e.g. the test cases of a `#[parameterized(runner, ...)]` test function.
+    pub fn trials(mut self, trials: impl IntoIterator<Item = Trial>) -> Self {
+        self.trials.extend(trials);
+        self
+    }
+
+    /// Adds a test for each case, which calls `test` with the value of the case. The name of each
+    /// test is `name::case`.
+    pub fn cases<C, T, R>(
+        mut self,
+        name: &str,
+        cases: impl IntoIterator<Item = (String, C)>,
+        test: T,
+    ) -> Self
+    where
+        C: Send + 'static,
+        T: Fn(C) -> R + Send + Sync + 'static,
+        R: Outcome,
+    {
+        let test = Arc::new(test);
+
+        self.trials.extend(cases.into_iter().map(|(case, value)| {
+            let test = Arc::clone(&test);
+
+            Trial::test(format!("{}::{}", name, case), move || test(value))
+        }));
+
+        self
+    }
+
+    /// Runs or lists the tests. Exits the process with code 101 when a test failed.
+    pub fn run(self) {
+        // Not locked for the whole run, since tests which print to stdout would block on the lock
+        let mut out = std::io::stdout();
+
+        if self.options.list {
+            self.options.list(&self.trials, &mut out).ok();
+            return;
+        }
+
+        let conclusion = self.options.execute(self.trials, &mut out);
+
+        if conclusion.failed > 0 {
+            std::process::exit(101);
+        }
+    }
+}
+
+/// A single named test.
+pub struct Trial {
+    name: String,
+    ignored: bool,
+    should_panic: ShouldPanic,
+    test: Box<dyn FnOnce() -> Result<(), Failed> + Send>,
+}
+
+impl Trial {
+    /// A test, which fails when `test` panics or returns an error.
+    pub fn test<R: Outcome>(
+        name: impl Into<String>,
+        test: impl FnOnce() -> R + Send + 'static,
+    ) -> Self {
+        Trial {
+            name: name.into(),
+            ignored: false,
+            should_panic: ShouldPanic::No,
+            test: Box::new(move || test().into_result()),
+        }
+    }
+
+    /// Whether the test is ignored, like a test with an `#[ignore]` attribute.
+    pub fn with_ignored_flag(mut self, ignored: bool) -> Self {
+        self.ignored = ignored;
+        self
+    }
+
+    /// Expects the test to panic, like a test with a `#[should_panic]` attribute. If `expected`
+    /// is given, the panic message must contain it.
+    pub fn with_should_panic(mut self, expected: Option<&str>) -> Self {
+        self.should_panic = match expected {
+            Some(expected) => ShouldPanic::WithMessage(expected.to_string()),
+            None => ShouldPanic::Yes,
+        };
+        self
+    }
+
+    /// The name of the test.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+enum ShouldPanic {
+    No,
+    Yes,
+    WithMessage(String),
+}
+
+/// The reason a test failed.
+#[derive(Debug)]
+pub struct Failed {
+    message: String,
+}
+
+impl<M: std::fmt::Display> From<M> for Failed {
+    fn from(message: M) -> Self {
+        Failed {
+            message: message.to_string(),
+        }
+    }
+}
+
+/// The return type of a test, like the return type of a `#[test]` function.
+pub trait Outcome {
+    fn into_result(self) -> Result<(), Failed>;
+}
+
+impl Outcome for () {
+    fn into_result(self) -> Result<(), Failed> {
+        Ok(())
+    }
+}
+
+impl<E: std::fmt::Debug> Outcome for Result<(), E> {
+    fn into_result(self) -> Result<(), Failed> {
+        self.map_err(|error| Failed::from(format!("Error: {:?}", error)))
+    }
+}
+
+/// The name of a test case of a `#[parameterized(runner, ...)]` test function, which is its
+/// module path without the name of the crate, like the names of `#[test]` functions.
+#[doc(hidden)]
+pub fn __case_name(module_path: &str, case: &str) -> String {
+    match module_path.split_once("::") {
+        Some((_, path)) => format!("{}::{}", path, case),
+        None => case.to_string(),
+    }
+}
+
+const USAGE: &str = "Usage: [OPTIONS] [FILTERS...]
+
+Options:
+        --include-ignored   Run ignored and not ignored tests
+        --ignored           Run only ignored tests
+        --list              List all tests
+        --exact             Exactly match filters rather than by substring
+        --skip FILTER       Skip tests whose names contain FILTER (this flag can be used multiple times)
+        --test-threads N    Number of threads used for running tests in parallel
+        --format pretty|terse
+                            Configure formatting of output
+    -q, --quiet             Display one character per test instead of one line
+    -h, --help              Display this message";
+
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    filters: Vec<String>,
+    skip: Vec<String>,
+    exact: bool,
+    ignored: Ignored,
+    list: bool,
+    terse: bool,
+    test_threads: Option<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Ignored {
+    Exclude,
+    Include,
+    Only,
+}
+
+impl Default for Ignored {
+    fn default() -> Self {
+        Ignored::Exclude
+    }
+}
+
+impl Options {
+    /// Parses the command line arguments, or returns `None` if the usage should be displayed.
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Option<Options>, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) if arg.starts_with("--") => {
+                    (flag.to_string(), Some(value.to_string()))
+                }
+                _ => (arg.clone(), None),
+            };
+            let mut value = |name: &str| {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("Argument to option '{}' missing", name))
+            };
+
+            match flag.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "--include-ignored" => options.ignored = Ignored::Include,
+                "--ignored" => options.ignored = Ignored::Only,
+                "--list" => options.list = true,
+                "--exact" => options.exact = true,
+                "-q" | "--quiet" => options.terse = true,
+                "--skip" => options.skip.push(value("skip")?),
+                "--format" => match value("format")?.as_str() {
+                    "pretty" => options.terse = false,
+                    "terse" => options.terse = true,
+                    format => {
+                        return Err(format!(
+                            "argument for --format must be pretty or terse (was {})",
+                            format
+                        ))
+                    }
+                },
+                "--test-threads" => {
+                    let threads = value("test-threads")?;
+
+                    match threads.parse::<usize>() {
+                        Ok(threads) if threads > 0 => options.test_threads = Some(threads),
+                        _ => {
+                            return Err(format!(
+                                "argument for --test-threads must be a number > 0 (was {})",
+                                threads
+                            ))
+                        }
+                    }
+                }
+                // The output of tests is never captured, but shown as the tests run
+                "--nocapture" | "--show-output" => {
+                    return Err(format!(
+                        "{} is not supported, since the runner doesn't capture the output of tests",
+                        flag
+                    ))
+                }
+                // Accepted for compatibility with the default test harness, but without effect
+                "--bench" | "--test" => {}
+                "--color" => {
+                    value("color")?;
+                }
+                flag if flag.starts_with('-') => {
+                    return Err(format!("Unrecognized option: '{}'", flag))
+                }
+                _ => options.filters.push(arg),
+            }
+        }
+
+        Ok(Some(options))
+    }
+
+    /// Whether the test should run or be listed, based on its name, and on whether it's ignored
+    /// when only ignored tests are selected.
+    fn is_selected(&self, trial: &Trial) -> bool {
+        let name = trial.name.as_str();
+        let matches = |filter: &String| {
+            if self.exact {
+                name == filter
+            } else {
+                name.contains(filter.as_str())
+            }
+        };
+
+        (self.filters.is_empty() || self.filters.iter().any(matches))
+            && !self.skip.iter().any(matches)
+            && (self.ignored != Ignored::Only || trial.ignored)
+    }
+
+    fn list(&self, trials: &[Trial], out: &mut impl Write) -> std::io::Result<()> {
+        let mut count = 0;
+
+        for trial in trials.iter().filter(|trial| self.is_selected(trial)) {
+            writeln!(out, "{}: test", trial.name)?;
+            count += 1;
+        }
+
+        if !self.terse {
+            writeln!(out)?;
+            writeln!(out, "{}, 0 benchmarks", plural(count, "test"))?;
+        }
+
+        Ok(())
+    }
+
+    fn execute(&self, trials: Vec<Trial>, out: &mut impl Write) -> Conclusion {
+        let start = Instant::now();
+        let total = trials.len();
+        let mut conclusion = Conclusion::default();
+
+        let (run, ignored): (Vec<_>, Vec<_>) = trials
+            .into_iter()
+            .filter(|trial| self.is_selected(trial))
+            .partition(|trial| !trial.ignored || self.ignored != Ignored::Exclude);
+
+        conclusion.filtered_out = total - run.len() - ignored.len();
+
+        writeln!(out).ok();
+        writeln!(out, "running {}", plural(run.len() + ignored.len(), "test")).ok();
+
+        for trial in &ignored {
+            self.report(out, &trial.name, Status::Ignored);
+            conclusion.ignored += 1;
+        }
+
+        let threads = self
+            .test_threads
+            .or_else(|| std::env::var("RUST_TEST_THREADS").ok()?.parse().ok())
+            .or_else(|| std::thread::available_parallelism().ok().map(Into::into))
+            .unwrap_or(1);
+
+        let mut failures = Vec::new();
+
+        for (name, result) in run_parallel(run, threads) {
+            match result {
+                Ok(()) => {
+                    self.report(out, &name, Status::Ok);
+                    conclusion.passed += 1;
+                }
+                Err(failed) => {
+                    self.report(out, &name, Status::Failed);
+                    conclusion.failed += 1;
+                    failures.push((name, failed));
+                }
+            }
+        }
+
+        if self.terse {
+            writeln!(out).ok();
+        }
+
+        if !failures.is_empty() {
+            writeln!(out).ok();
+            writeln!(out, "failures:").ok();
+
+            for (name, failed) in &failures {
+                writeln!(out).ok();
+                writeln!(out, "---- {} failure ----", name).ok();
+                writeln!(out, "{}", failed.message).ok();
+            }
+
+            writeln!(out).ok();
+            writeln!(out, "failures:").ok();
+
+            for (name, _) in &failures {
+                writeln!(out, "    {}", name).ok();
+            }
+        }
+
+        writeln!(out).ok();
+        writeln!(
+            out,
+            "test result: {}. {} passed; {} failed; {} ignored; 0 measured; {} filtered out; finished in {:.2}s",
+            if conclusion.failed > 0 { "FAILED" } else { "ok" },
+            conclusion.passed,
+            conclusion.failed,
+            conclusion.ignored,
+            conclusion.filtered_out,
+            start.elapsed().as_secs_f64(),
+        )
+        .ok();
+        writeln!(out).ok();
+
+        conclusion
+    }
+
+    fn report(&self, out: &mut impl Write, name: &str, status: Status) {
+        if self.terse {
+            let c = match status {
+                Status::Ok => '.',
+                Status::Failed => 'F',
+                Status::Ignored => 'i',
+            };
+
+            write!(out, "{}", c).ok();
+        } else {
+            let status = match status {
+                Status::Ok => "ok",
+                Status::Failed => "FAILED",
+                Status::Ignored => "ignored",
+            };
+
+            writeln!(out, "test {} ... {}", name, status).ok();
+        }
+
+        out.flush().ok();
+    }
+}
+
+enum Status {
+    Ok,
+    Failed,
+    Ignored,
+}
+
+/// The number of tests with each result.
+#[derive(Debug, Default, PartialEq)]
+struct Conclusion {
+    passed: usize,
+    failed: usize,
+    ignored: usize,
+    filtered_out: usize,
+}
+
+/// Runs the tests on a pool of worker threads. Each test runs on its own thread, which is named
+/// after the test, like with the default test harness. The results are returned as the tests
+/// complete.
+fn run_parallel(
+    trials: Vec<Trial>,
+    threads: usize,
+) -> mpsc::IntoIter<(String, Result<(), Failed>)> {
+    let workers = threads.min(trials.len());
+    let queue = Arc::new(Mutex::new(trials.into_iter()));
+    let (sender, receiver) = mpsc::channel();
+
+    for _ in 0..workers {
+        let queue = Arc::clone(&queue);
+        let sender = sender.clone();
+
+        std::thread::spawn(move || loop {
+            let trial = match queue.lock().map(|mut queue| queue.next()) {
+                Ok(Some(trial)) => trial,
+                _ => break,
+            };
+
+            let name = trial.name.clone();
+            let result = run_trial(trial);
+
+            if sender.send((name, result)).is_err() {
+                break;
+            }
+        });
+    }
+
+    receiver.into_iter()
+}
+
+fn run_trial(trial: Trial) -> Result<(), Failed> {
+    let test = trial.test;
+    let result = std::thread::Builder::new()
+        .name(trial.name)
+        .spawn(test)
+        .map_err(|error| Failed::from(format!("unable to spawn a thread for the test: {}", error)))?
+        .join();
+
+    match (result, trial.should_panic) {
+        (Ok(result), ShouldPanic::No) => result,
+        (Ok(_), _) => Err(Failed::from("note: test did not panic as expected")),
+        (Err(_), ShouldPanic::Yes) => Ok(()),
+        (Err(payload), ShouldPanic::WithMessage(expected)) => {
+            let message = panic_message(payload.as_ref());
+
+            if message.contains(&expected) {
+                Ok(())
+            } else {
+                Err(Failed::from(format!(
+                    "panic did not contain expected string\n      panic message: `{:?}`,\n expected substring: `{:?}`",
+                    message, expected
+                )))
+            }
+        }
+        (Err(payload), ShouldPanic::No) => Err(Failed::from(panic_message(payload.as_ref()))),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Conclusion, Ignored, Options, Trial};
+
+    fn options(args: &[&str]) -> Options {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+            .unwrap()
+            .unwrap()
+    }
+
+    fn boom() {
+        panic!("boom");
+    }
+
+    fn trials() -> Vec<Trial> {
+        vec![
+            Trial::test("math::add::one", || assert_eq!(1 + 1, 2_i32.pow(1))),
+            Trial::test("math::add::two", || assert_eq!(1 + 1, 3_i32.pow(1))),
+            Trial::test("math::sub::one", || Err::<(), _>("not implemented")),
+            Trial::test("slow", || {}).with_ignored_flag(true),
+            Trial::test("panics", boom).with_should_panic(Some("boom")),
+        ]
+    }
+
+    fn execute(args: &[&str]) -> (Conclusion, String) {
+        let mut out = Vec::new();
+        let conclusion = options(args).execute(trials(), &mut out);
+
+        (conclusion, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn parses_arguments() {
+        assert_eq!(
+            options(&[
+                "add",
+                "--exact",
+                "--skip",
+                "two",
+                "--skip=sub",
+                "--include-ignored",
+                "--test-threads",
+                "2",
+                "--format=terse",
+            ]),
+            Options {
+                filters: vec!["add".to_string()],
+                skip: vec!["two".to_string(), "sub".to_string()],
+                exact: true,
+                ignored: Ignored::Include,
+                list: false,
+                terse: true,
+                test_threads: Some(2),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_arguments() {
+        assert!(Options::parse(vec!["--unknown".to_string()]).is_err());
+        assert!(Options::parse(vec!["--test-threads".to_string(), "0".to_string()]).is_err());
+        assert!(Options::parse(vec!["--nocapture".to_string()]).is_err());
+        assert!(Options::parse(vec!["--show-output".to_string()]).is_err());
+    }
+
+    #[test]
+    fn runs_all_tests() {
+        let (conclusion, out) = execute(&["--test-threads", "1"]);
+
+        assert_eq!(
+            conclusion,
+            Conclusion {
+                passed: 2,
+                failed: 2,
+                ignored: 1,
+                filtered_out: 0,
+            }
+        );
+        assert!(out.contains("running 5 tests\n"));
+        assert!(out.contains("test math::add::two ... FAILED\n"));
+        assert!(out.contains("---- math::sub::one failure ----\nError: \"not implemented\"\n"));
+        assert!(out.contains("failures:\n    math::add::two\n    math::sub::one\n"));
+        assert!(out.contains(
+            "test result: FAILED. 2 passed; 2 failed; 1 ignored; 0 measured; 0 filtered out;"
+        ));
+    }
+
+    #[test]
+    fn filters_tests() {
+        let (conclusion, _) = execute(&["add", "--skip", "two"]);
+
+        assert_eq!(
+            conclusion,
+            Conclusion {
+                passed: 1,
+                failed: 0,
+                ignored: 0,
+                filtered_out: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn filters_exact_names() {
+        let (conclusion, _) = execute(&["--exact", "math::add"]);
+
+        assert_eq!(conclusion.filtered_out, 5);
+    }
+
+    #[test]
+    fn runs_only_ignored_tests() {
+        let (conclusion, out) = execute(&["--ignored"]);
+
+        assert_eq!(conclusion.passed, 1);
+        assert_eq!(conclusion.filtered_out, 4);
+        assert!(out.contains("test slow ... ok\n"));
+    }
+
+    #[test]
+    fn lists_tests() {
+        let mut out = Vec::new();
+        options(&["--list", "add"])
+            .list(&trials(), &mut out)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "math::add::one: test\nmath::add::two: test\n\n2 tests, 0 benchmarks\n"
+        );
+    }
+
+    #[test]
+    fn lists_only_ignored_tests() {
+        let mut out = Vec::new();
+        options(&["--list", "--ignored"])
+            .list(&trials(), &mut out)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "slow: test\n\n1 test, 0 benchmarks\n"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+/// Implemented for tuples with `N` elements, and for structs with `N` fields which derive
+/// `yare::Spread`.
+///
+/// A tuple which is spread over the parameters of a test function, e.g. `{ ..CONSTANT }`, must have
+/// an element for each of the `N` parameters it is spread over. Otherwise, the test case fails to
+/// compile, because this trait isn't implemented for the tuple.
+pub trait SpreadOverParameters<const N: usize> {
+    type Tuple;
+
+    fn spread(self) -> Self::Tuple;
+}
+
+macro_rules! spread_over_parameters {
+    ($($n:literal => ($($element:ident),+)),+ $(,)?) => {
+        $(
+            impl<$($element),+> SpreadOverParameters<$n> for ($($element,)+) {
+                type Tuple = Self;
+
+                fn spread(self) -> Self {
+                    self
+                }
+            }
+        )+
+    };
+}
+
+spread_over_parameters! {
+    1 => (A),
+    2 => (A, B),
+    3 => (A, B, C),
+    4 => (A, B, C, D),
+    5 => (A, B, C, D, E),
+    6 => (A, B, C, D, E, F),
+    7 => (A, B, C, D, E, F, G),
+    8 => (A, B, C, D, E, F, G, H),
+    9 => (A, B, C, D, E, F, G, H, I),
+    10 => (A, B, C, D, E, F, G, H, I, J),
+    11 => (A, B, C, D, E, F, G, H, I, J, K),
+    12 => (A, B, C, D, E, F, G, H, I, J, K, L),
+}
+
+/// Checks that a spread tuple has an element for each of the `N` parameters it is spread over.
+/// When it doesn't, the tuple type can't be resolved, so only the missing implementation of
+/// `SpreadOverParameters` is reported, and not the mismatch with the types of the parameters.
+pub fn spread<T: SpreadOverParameters<N>, const N: usize>(tuple: T) -> T::Tuple {
+    tuple.spread()
+}
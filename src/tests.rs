@@ -1,8 +1,29 @@
 mod arguments_are_expressions;
+#[cfg(feature = "criterion")]
+mod bench;
+mod boundaries;
+mod case_attributes;
+mod case_info;
+mod case_sets;
+mod cfg_select;
+mod const_eval;
+mod conversions;
+mod data_file;
 mod dice;
+mod doc_table;
+mod extends;
+mod file_glob;
 mod fruits;
+mod generators;
+mod golden;
 mod marked_test;
+mod parameterized_mod;
+mod parameterized_types;
 mod qualifiers;
 mod readme;
+mod rest;
 mod return_type;
 mod should_panic;
+mod spread;
+mod stacked_attributes;
+mod variants;
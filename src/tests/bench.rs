@@ -0,0 +1,59 @@
+use crate::{parameterized, parameterized_bench};
+use criterion::Criterion;
+use std::time::Duration;
+
+#[parameterized_bench(
+    empty = { "" },
+    word = { "yare" },
+    sentence = { "yet another parameterized test macro" },
+)]
+fn count_vowels(text: &str) -> usize {
+    text.chars().filter(|c| "aeiou".contains(*c)).count()
+}
+
+#[parameterized(bench,
+    small = { vec![3, 1, 2] },
+    reversed = { (0..100).rev().collect() },
+)]
+fn sort(values: Vec<u32>) -> Result<(), String> {
+    let mut values = values;
+    values.sort_unstable();
+
+    match values.windows(2).all(|pair| pair[0] <= pair[1]) {
+        true => Ok(()),
+        false => Err(format!("unsorted: {:?}", values)),
+    }
+}
+
+#[parameterized_bench(
+    no_arguments = {},
+)]
+fn case_info() {
+    assert_eq!(yare::case!().name, "no_arguments");
+}
+
+fn quick() -> Criterion {
+    Criterion::default()
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(1))
+        .measurement_time(Duration::from_millis(10))
+        .without_plots()
+}
+
+#[test]
+fn benchmark_per_case() {
+    let mut criterion = quick();
+
+    count_vowels::empty(&mut criterion);
+    count_vowels::word(&mut criterion);
+    count_vowels::sentence(&mut criterion);
+    sort::small(&mut criterion);
+    sort::reversed(&mut criterion);
+    case_info::no_arguments(&mut criterion);
+}
+
+#[test]
+fn criterion_group() {
+    let _: fn() = count_vowels::benches;
+    let _: fn() = sort::benches;
+}
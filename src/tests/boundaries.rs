@@ -0,0 +1,77 @@
+use crate::parameterized;
+
+#[parameterized(boundaries(n))]
+fn unsigned(n: u8) {
+    let expected = match yare::case!().name {
+        "n_min" => 0,
+        "n_max" => u8::MAX,
+        "n_one" => 1,
+        "n_max_minus_one" => u8::MAX - 1,
+        name => panic!("unexpected test case `{}`", name),
+    };
+
+    assert_eq!(n, expected);
+}
+
+#[parameterized(
+    boundaries(n),
+    seven = { 7 },
+)]
+fn signed(n: i64) {
+    assert_eq!(n.checked_abs().is_none(), n == i64::MIN);
+}
+
+#[parameterized(boundaries(x))]
+fn float(x: f32) {
+    let expected = match yare::case!().name {
+        "x_min" => f32::MIN,
+        "x_max" => f32::MAX,
+        "x_zero" => 0.0,
+        "x_negative_zero" => -0.0,
+        "x_one" => 1.0,
+        "x_minus_one" => -1.0,
+        "x_epsilon" => f32::EPSILON,
+        "x_infinity" => f32::INFINITY,
+        "x_neg_infinity" => f32::NEG_INFINITY,
+        "x_nan" => return assert!(x.is_nan()),
+        name => panic!("unexpected test case `{}`", name),
+    };
+
+    // Also distinguishes `0.0` from `-0.0`
+    assert_eq!(x.to_bits(), expected.to_bits());
+}
+
+#[parameterized(boundaries(n, divisor = 2), boundaries(divisor, n = 100))]
+fn with_other_parameters(n: u32, divisor: u32) {
+    assert!(n
+        .checked_div(divisor)
+        .map_or(true, |quotient| quotient <= n));
+}
+
+#[parameterized(boundaries(n))]
+fn named_after_the_value(n: i16) {
+    let expected = match yare::case!().name {
+        "n_min" => i16::MIN,
+        "n_max" => i16::MAX,
+        "n_zero" => 0,
+        "n_one" => 1,
+        "n_minus_one" => -1,
+        "n_max_minus_one" => i16::MAX - 1,
+        name => panic!("unexpected test case `{}`", name),
+    };
+
+    assert_eq!(n, expected);
+}
+
+#[parameterized(boundaries(x))]
+fn named_after_the_float_value(x: f64) {
+    let case = yare::case!();
+
+    match case.name {
+        "x_nan" => assert!(x.is_nan()),
+        "x_negative_zero" => assert!(x == 0.0 && x.is_sign_negative()),
+        "x_epsilon" => assert_eq!(x, f64::EPSILON),
+        "x_neg_infinity" => assert_eq!(x, f64::NEG_INFINITY),
+        _ => assert!(!x.is_nan()),
+    }
+}
@@ -0,0 +1,48 @@
+use crate::parameterized;
+
+fn add5<T: Into<u32>>(component: T) -> u32 {
+    component.into() + 5
+}
+
+#[parameterized]
+#[case(zero_plus_five, 0, 5)]
+#[case(one_plus_five, 1, 6)]
+#[case::twenty_plus_five(20, 25)]
+fn add_five(input: u16, expected: u32) {
+    assert_eq!(add5(input), expected);
+}
+
+#[parameterized(
+    zero_plus_five = { 0, 5 },
+)]
+#[case::one_plus_five(1, 6)]
+fn mixed_with_attribute_cases(input: u16, expected: u32) {
+    assert_eq!(add5(input), expected);
+}
+
+#[parameterized()]
+#[case(first)]
+#[case::second()]
+fn zero_parameters() {
+    assert!(["first", "second"].contains(&yare::case!().name));
+}
+
+#[parameterized(
+    first = { 0 },
+)]
+#[case(second, 1)]
+#[parameterized(
+    third = { 2 },
+)]
+#[case(fourth, 3)]
+fn follow_their_attribute(index: usize) {
+    assert_eq!(yare::case!().index, index);
+}
+
+#[parameterized]
+#[case(first, 0)]
+#[parameterized]
+#[case(second, 1)]
+fn stacked_without_arguments(index: usize) {
+    assert_eq!(yare::case!().index, index);
+}
@@ -0,0 +1,52 @@
+use yare::parameterized;
+
+#[parameterized(
+    first = { 1 + 1 },
+    second = { 2 },
+)]
+fn describes_the_running_case(value: u32) {
+    let info = yare::case!();
+
+    assert_eq!(info.test_fn, "describes_the_running_case");
+    assert!(info
+        .module_path
+        .ends_with("tests::case_info::describes_the_running_case"));
+    assert_eq!(info.args.len(), 1);
+    assert_eq!(info.args[0].0, "value");
+
+    match info.name {
+        "first" => {
+            assert_eq!(info.index, 0);
+            assert_eq!(info.args[0].1, "1 + 1");
+        }
+        "second" => assert_eq!(info.index, 1),
+        other => panic!("unexpected test case {}", other),
+    }
+
+    assert_eq!(value, 2);
+}
+
+#[parameterized(
+    one = { },
+    two = { },
+)]
+fn seeds_differ_per_case() {
+    let info = yare::case!();
+    let other = if info.name == "one" { "two" } else { "one" };
+
+    let other_seed = yare::CaseInfo {
+        name: other,
+        ..*info
+    }
+    .seed();
+
+    assert_ne!(info.seed(), other_seed);
+}
+
+#[parameterized(
+    only = { 3 },
+)]
+const fn available_in_const_fn(value: u32) {
+    assert!(yare::case!().index == 0);
+    assert!(value == 3);
+}
@@ -0,0 +1,50 @@
+use yare::parameterized;
+
+yare::cases! {
+    pub(crate) ASCII = {
+        empty = { "" },
+        letters = { "abc" },
+    },
+    NON_ASCII = {
+        umlaut = { "ü" },
+        use ASCII,
+    },
+}
+
+mod nested {
+    yare::cases! {
+        pub EMOJI = {
+            sailboat = { "⛵" },
+        }
+    }
+}
+
+// A public case set with the same name as one in another module
+mod other {
+    yare::cases! {
+        pub EMOJI = {
+            crab = { "🦀" },
+        }
+    }
+}
+
+#[parameterized(use ASCII)]
+fn is_ascii(input: &str) {
+    assert!(input.is_ascii());
+}
+
+#[parameterized(use NON_ASCII, use nested::EMOJI, extra = { "yare" })]
+fn is_valid_utf8(input: &str) {
+    assert!(std::str::from_utf8(input.as_bytes()).is_ok());
+}
+
+#[parameterized(use nested::EMOJI, use other::EMOJI)]
+fn same_name_in_other_modules(input: &str) {
+    let expected = match yare::case!().name {
+        "sailboat" => "⛵",
+        "crab" => "🦀",
+        name => panic!("unexpected test case `{}`", name),
+    };
+
+    assert_eq!(input, expected);
+}
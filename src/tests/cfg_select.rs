@@ -0,0 +1,70 @@
+use crate::parameterized;
+
+#[parameterized(
+    separator = { std::path::MAIN_SEPARATOR, cfg_select { unix => '/', not(unix) => '\\' } },
+)]
+fn negated_predicate(actual: char, expected: char) {
+    assert_eq!(actual, expected);
+}
+
+#[parameterized(
+    test_build = { cfg_select { test => "test", _ => "not test" } },
+)]
+fn fallback(build: &str) {
+    assert_eq!(build, "test");
+}
+
+#[parameterized(
+    first_match = { cfg_select { test => 1, all(test, debug_assertions) => 2, _ => 3 } },
+)]
+fn first_alternative_which_holds(n: u32) {
+    assert_eq!(n, 1);
+}
+
+#[parameterized(
+    always = { cfg_select { all() => "always" } },
+)]
+fn always(value: &str) {
+    assert_eq!(value, "always");
+}
+
+#[parameterized(
+    proptest = { cfg_select { feature = "proptest" => true, _ => false } },
+)]
+fn feature(enabled: bool) {
+    assert_eq!(enabled, cfg!(feature = "proptest"));
+}
+
+#[parameterized(
+    parsed = { cfg_select { test => "42", _ => "0" } },
+)]
+fn converted(#[from_str] n: u32) {
+    assert_eq!(n, 42);
+}
+
+#[parameterized(
+    base = { 1, 2 },
+    overridden = base { b = cfg_select { test => 3, _ => 4 } },
+)]
+fn extended(a: u32, b: u32) {
+    assert!(a == 1 && (b == 2 || b == 3));
+}
+
+crate::cases! {
+    SEPARATORS = {
+        unix_or_not = { cfg_select { unix => "/", not(unix) => "\\" } },
+    },
+}
+
+#[parameterized(use SEPARATORS)]
+fn case_set(separator: &str) {
+    assert_eq!(separator.len(), 1);
+}
+
+#[parameterized(
+    attribute = { 0 },
+)]
+#[case::from_attribute(cfg_select { test => 5, _ => 6 })]
+fn case_attribute(n: u32) {
+    assert!(n == 0 || n == 5);
+}
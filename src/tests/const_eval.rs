@@ -0,0 +1,28 @@
+use crate::parameterized;
+
+const fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[parameterized(const_eval,
+    coprime = { 8, 9, 1 },
+    common = { 12, 18, 6 },
+    zero = { 7, 0, 7 },
+)]
+const fn greatest_common_divisor(a: u64, b: u64, expected: u64) {
+    assert!(gcd(a, b) == expected);
+}
+
+const LIMIT: u32 = 3;
+
+#[parameterized(const_eval,
+    below = { LIMIT - 1 },
+    at = { LIMIT },
+)]
+const fn constant_arguments(value: u32) {
+    assert!(value <= LIMIT);
+}
@@ -0,0 +1,53 @@
+use crate::parameterized;
+use std::net::Ipv4Addr;
+use std::path::PathBuf;
+
+#[parameterized(
+    localhost = { "127.0.0.1", true },
+    public = { "1.1.1.1", false },
+)]
+fn from_str(#[from_str] address: Ipv4Addr, loopback: bool) {
+    assert_eq!(address.is_loopback(), loopback);
+}
+
+#[parameterized(
+    file = { "dir/file.txt", "txt" },
+    owned = { String::from("archive.tar.gz"), "gz" },
+)]
+fn into(#[into] path: PathBuf, extension: &str) {
+    assert_eq!(path.extension().unwrap(), extension);
+}
+
+#[parameterized(
+    both = { "42", 'x', 42 },
+    expression = { &format!("{}", 7), 'y', 7 },
+)]
+fn mixed(#[from_str] number: u64, #[into] code: u32, expected: u64) {
+    assert_eq!(number, expected);
+    assert!(code > 0);
+}
+
+const VECTOR: (&str, &str) = ("10.0.0.1", "config.toml");
+
+#[parameterized(
+    spread = { ..VECTOR },
+)]
+fn spread(#[from_str] address: Ipv4Addr, #[into] config: PathBuf) {
+    assert!(address.is_private());
+    assert_eq!(config, PathBuf::from("config.toml"));
+}
+
+#[parameterized(file = "src/tests/data/addition.csv")]
+fn data_file_is_converted_already(#[from_str] left: i32, #[into] right: i32, sum: i32) {
+    assert_eq!(left + right, sum);
+}
+
+#[parameterized(
+    invalid = { "not an address" },
+)]
+#[should_panic(
+    expected = "yare: unable to parse \"not an address\" for parameter `address` in test case `invalid_address::invalid`"
+)]
+fn invalid_address(#[from_str] address: Ipv4Addr) {
+    let _ = address;
+}
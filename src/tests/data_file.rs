@@ -0,0 +1,50 @@
+use crate::parameterized;
+
+#[parameterized(file = "src/tests/data/addition.csv")]
+fn csv(left: i32, right: i32, sum: i32) {
+    assert_eq!(left + right, sum);
+}
+
+#[parameterized(file = "src/tests/data/parse.json")]
+fn json(input: &str, expected: Option<f64>, valid: bool) {
+    assert_eq!(input.parse::<f64>().ok(), expected);
+    assert_eq!(expected.is_some(), valid);
+}
+
+#[parameterized(file("src/tests/data/words.toml", name = "id"))]
+fn toml(word: String, letters: Vec<char>) {
+    assert_eq!(word.chars().collect::<Vec<_>>(), letters);
+}
+
+#[parameterized(
+    file = "src/tests/data/addition.csv",
+    explicit = { 2, 2, 4 },
+)]
+fn mixed_with_test_cases(left: i64, right: i64, sum: i64) {
+    assert_eq!(left + right, sum);
+}
+
+#[parameterized(file = "src/tests/data/addresses.csv")]
+fn parsed_from_str(address: std::net::IpAddr, loopback: bool) {
+    assert_eq!(address.is_loopback(), loopback);
+}
+
+#[parameterized(file = "src/tests/data/addition.csv")]
+fn case_info(left: i32) {
+    let case = crate::case!();
+
+    assert_eq!(case.file, "src/tests/data/addition.csv");
+    assert_eq!(case.args[0], ("left", &*left.to_string()));
+}
+
+#[parameterized(file = "src/tests/data/floats.csv")]
+fn floats_out_of_range(single: f32, double: f64, class: &str) {
+    let expected = match class {
+        "finite" => (0.5, 0.5),
+        "infinite" => (f32::INFINITY, f64::INFINITY),
+        "negative_infinite" => (f32::NEG_INFINITY, f64::NEG_INFINITY),
+        _ => return assert!(single.is_nan() && double.is_nan()),
+    };
+
+    assert_eq!((single, double), expected);
+}
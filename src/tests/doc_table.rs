@@ -0,0 +1,55 @@
+use crate::parameterized;
+
+/// Adds two numbers.
+///
+/// | case       | a  | b  | expected |
+/// |------------|----|----|----------|
+/// | zero       | 0  | 0  | 0        |
+/// | one        | 1  | 0  | 1        |
+/// | both       | 2  | 3  | 5        |
+/// | Big Number | 40 | 2  | 42       |
+#[parameterized(doc_table)]
+fn add(a: u32, b: u32, expected: u32) {
+    assert_eq!(a + b, expected);
+}
+
+/// The columns may be given in any order, and cells may be code spans.
+///
+/// | name    | expected | text          |
+/// |:--------|---------:|:-------------:|
+/// | empty   | 0        | `""`          |
+/// | sailing | 3        | `"⛵⛵⛵"`      |
+/// | piped   | 1        | `"\|"`        |
+#[parameterized(doc_table)]
+fn length(text: &str, expected: usize) {
+    assert_eq!(text.chars().count(), expected);
+}
+
+/// | case | n |
+/// |------|---|
+/// | two  | 2 |
+#[parameterized(doc_table, four = { 4 })]
+fn mixed_with_test_cases(n: u32) {
+    assert_eq!(n % 2, 0);
+}
+
+/// | case  | n  |
+/// |-------|----|
+/// | first | 1  |
+#[parameterized(doc_table)]
+fn case_name(n: u32) {
+    assert_eq!(n, 1);
+    assert_eq!(yare::case!().name, "first");
+}
+
+/// A table in a block doc comment.
+/**
+| case | n |
+|------|---|
+| one  | 1 |
+| two  | 2 |
+*/
+#[parameterized(doc_table)]
+fn block_comment(n: u32) {
+    assert!(n == 1 || n == 2);
+}
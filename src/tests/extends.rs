@@ -0,0 +1,53 @@
+use crate::parameterized;
+
+#[parameterized(
+    base = { "localhost", 8080, true },
+    bad_port = base { port = 0 },
+    remote = extends(base) { host = "example.com", secure = false },
+    remote_bad_port = remote { port = 0 },
+)]
+fn connect(host: &str, port: u16, secure: bool) {
+    let name = yare::case!().name;
+
+    match name {
+        "base" => assert_eq!((host, port, secure), ("localhost", 8080, true)),
+        "bad_port" => assert_eq!((host, port, secure), ("localhost", 0, true)),
+        "remote" => assert_eq!((host, port, secure), ("example.com", 8080, false)),
+        "remote_bad_port" => assert_eq!((host, port, secure), ("example.com", 0, false)),
+        _ => unreachable!("{}", name),
+    }
+}
+
+#[parameterized(
+    doubled = base { n = 4, expected = 16 },
+    base = { 2, 4 },
+)]
+fn base_may_follow(n: u32, expected: u32) {
+    assert_eq!(n * n, expected);
+}
+
+#[parameterized(
+    unchanged = base {},
+    base = { 3 },
+)]
+fn without_overrides(n: u32) {
+    assert_eq!(n, 3);
+}
+
+#[parameterized(base = { 1, "one" })]
+#[parameterized(other = base { n = 2 })]
+fn across_attributes(n: u32, text: &str) {
+    assert_eq!(text, "one");
+    assert!(n == 1 || n == 2);
+}
+
+#[parameterized(
+    base = { 1 },
+    extended = base { n = 2 },
+)]
+fn case_info(n: u32) {
+    if yare::case!().name == "extended" {
+        assert_eq!(yare::case!().args, &[("n", "2")]);
+        assert_eq!(n, 2);
+    }
+}
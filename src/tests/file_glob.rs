@@ -0,0 +1,36 @@
+use crate::parameterized;
+use std::path::{Path, PathBuf};
+
+#[parameterized(files = "src/tests/fixtures/uppercase/**/*.input")]
+fn contents(input: &str) {
+    assert!(!input.is_empty());
+}
+
+#[parameterized(files = "src/tests/fixtures/uppercase/**/*.input")]
+fn with_expected(input: String, expected: Option<&str>) {
+    if let Some(expected) = expected {
+        assert_eq!(input.to_uppercase(), expected);
+    }
+}
+
+#[parameterized(files = "src/tests/fixtures/uppercase/*.input")]
+fn paths(input: &Path, expected: Option<PathBuf>) {
+    assert!(input.is_file());
+    assert_eq!(
+        expected.is_some(),
+        input.file_stem() == Some("word".as_ref())
+    );
+}
+
+#[parameterized(files = "src/tests/fixtures/uppercase/nested/*.input")]
+fn bytes(input: &[u8], expected: Vec<u8>) {
+    assert_eq!(input.to_ascii_uppercase(), expected);
+}
+
+#[parameterized(
+    files = "src/tests/fixtures/uppercase/word.input",
+    explicit = { "yare", Some("YARE") },
+)]
+fn mixed_with_test_cases(input: &str, expected: Option<&str>) {
+    assert_eq!(Some(&*input.to_uppercase()), expected);
+}
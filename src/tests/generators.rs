@@ -0,0 +1,57 @@
+use crate::parameterized;
+
+#[parameterized(values(1, 2, 3))]
+fn values(n: u32) {
+    assert_eq!(yare::case!().name, format!("value_{}", n - 1));
+}
+
+#[parameterized(values("yare", "⛵", concat!("ya", "re")))]
+fn values_are_expressions(text: &str) {
+    assert!(text.len() <= 4);
+}
+
+#[parameterized(values(1, 2), values(3))]
+fn values_are_numbered_across_lists(n: u32) {
+    assert_eq!(yare::case!().name, format!("value_{}", n - 1));
+}
+
+#[parameterized(values(1, 2))]
+#[parameterized(values(3))]
+fn values_are_numbered_across_attributes(n: u32) {
+    assert_eq!(yare::case!().name, format!("value_{}", n - 1));
+}
+
+#[parameterized(range(0..16))]
+fn range(n: u8) {
+    assert!(n < 16);
+    assert_eq!(yare::case!().name, format!("range_{}", n));
+}
+
+#[parameterized(range(0..=1000, step = 100))]
+fn range_with_step(n: u32) {
+    assert_eq!(n % 100, 0);
+    assert!(n <= 1000);
+}
+
+#[parameterized(range(-2..=2_i8))]
+fn negative_range(n: i8) {
+    let name = match n {
+        -2 => "range_minus_2",
+        -1 => "range_minus_1",
+        0 => "range_0",
+        1 => "range_1",
+        2 => "range_2",
+        _ => unreachable!(),
+    };
+
+    assert_eq!(yare::case!().name, name);
+}
+
+#[parameterized(
+    range(1..3),
+    values(10, 20),
+    hundred = { 100 },
+)]
+fn mixed_with_test_cases(n: i64) {
+    assert!([1, 2, 10, 20, 100].contains(&n));
+}
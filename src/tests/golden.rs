@@ -0,0 +1,41 @@
+use crate::parameterized;
+
+#[parameterized(
+    golden = "src/tests/golden/{fn}/{case}.txt",
+    empty = { "" },
+    words = { "yet another rust example" },
+    lines = { "first line\nsecond line" },
+)]
+fn title_case(input: &str) {
+    let actual = input
+        .lines()
+        .map(|line| {
+            line.split(' ')
+                .filter(|word| !word.is_empty())
+                .map(|word| word[..1].to_uppercase() + &word[1..])
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    yare::golden!().assert_eq(&actual);
+}
+
+#[parameterized(
+    golden = "src/tests/golden/{fn}/{case}.txt",
+    single = { 1 },
+    many = { 3 },
+)]
+fn golden_path(count: usize) {
+    let golden = yare::golden!();
+
+    assert_eq!(
+        golden.path(),
+        std::path::Path::new("src/tests/golden/golden_path")
+            .join(crate::case!().name)
+            .with_extension("txt")
+    );
+
+    golden.assert_eq(&"*".repeat(count));
+}
@@ -0,0 +1,34 @@
+use yare::parameterized;
+
+fn parse(input: &str) -> Option<u32> {
+    input.parse().ok()
+}
+
+#[parameterized(
+    zero = { "0", 0 },
+    one = { "1", 1 },
+    large = { "4294967295", u32::MAX },
+)]
+mod numbers {
+    use super::*;
+
+    // Not a test function, since it isn't marked with #[test]
+    fn digits(input: &str) -> usize {
+        input.chars().filter(char::is_ascii_digit).count()
+    }
+
+    #[test]
+    fn parses(input: &str, expected: u32) {
+        assert_eq!(parse(input), Some(expected));
+    }
+
+    #[test]
+    fn roundtrips(input: &str, expected: u32) {
+        assert_eq!(expected.to_string(), input);
+    }
+
+    #[test]
+    fn counts_digits(input: &str, expected: u32) {
+        assert_eq!(digits(input), expected.to_string().len());
+    }
+}
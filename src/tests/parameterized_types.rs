@@ -0,0 +1,56 @@
+use crate::parameterized_types;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+struct Config {
+    _name: String,
+}
+
+struct Handle {
+    _state: Arc<Mutex<u32>>,
+}
+
+#[parameterized_types(
+    requires = Send + Sync + 'static,
+    config = Config,
+    handle = Handle,
+    shared = Arc<Mutex<Vec<u8>>>,
+)]
+pub(crate) fn thread_safe<T: Send + Sync + 'static>() {
+    assert!(std::mem::size_of::<T>() > 0);
+}
+
+#[parameterized_types(
+    forbids = Clone + Copy,
+    config = Config,
+    handle = Handle,
+)]
+fn not_clone<T>() {}
+
+#[parameterized_types(
+    requires = 'static,
+    forbids = Send + Sync,
+    rc = Rc<u32>,
+    cell = Rc<Cell<u8>>,
+)]
+pub(crate) fn single_threaded<T: 'static>() -> Result<(), String> {
+    match std::mem::size_of::<T>() {
+        0 => Err(String::from("zero-sized")),
+        _ => Ok(()),
+    }
+}
+
+#[parameterized_types(
+    unit = (),
+)]
+#[should_panic]
+fn attributes_are_kept<T>() {
+    assert!(std::mem::size_of::<T>() > 0);
+}
+
+#[test]
+fn module_per_test_fn() {
+    thread_safe::config();
+    single_threaded::rc().unwrap();
+}
@@ -4,6 +4,7 @@ use yare::parameterized;
     purple = { &[128, 0, 128] },
     orange = { &[255, 127, 0] },
 )]
+#[allow(clippy::unnecessary_first_then_check)]
 const extern "C" fn has_reds(streamed_color: &[u8]) {
     assert!(streamed_color.first().is_some());
 }
@@ -0,0 +1,54 @@
+use crate::parameterized;
+
+#[parameterized(
+    none = { 0 },
+    short = { 1, "a" },
+    long = { 3, "a", "b", "c" },
+)]
+fn vec(expected: usize, #[rest] items: Vec<&str>) {
+    assert_eq!(items.len(), expected);
+}
+
+#[parameterized(
+    short = { 1, "a".to_string() },
+    long = { 2, "a".to_string(), "b".repeat(2) },
+)]
+fn slice(expected: usize, #[rest] items: &[String]) {
+    assert_eq!(items.len(), expected);
+}
+
+#[parameterized(
+    one = { 1 },
+    two = { 1, 2 },
+    three = { 1, 2, 3 },
+)]
+fn only_rest(#[rest] numbers: Vec<u32>) {
+    assert_eq!(
+        numbers.iter().sum::<u32>(),
+        numbers.len() as u32 * (numbers.len() as u32 + 1) / 2
+    );
+}
+
+#[parameterized(
+    triple = { "abc", 'a', 'b', 'c' },
+)]
+fn array(text: &str, #[rest] chars: [char; 3]) {
+    assert_eq!(text.chars().collect::<Vec<_>>(), chars);
+}
+
+#[parameterized(
+    several = { "total", 1, 2, 3 },
+)]
+fn case_info(name: &str, #[rest] numbers: Vec<u8>) {
+    assert_eq!(name, "total");
+    assert_eq!(numbers, vec![1, 2, 3]);
+    assert_eq!(
+        yare::case!().args,
+        &[("name", "\"total\""), ("numbers", "[1, 2, 3]")]
+    );
+}
+
+#[parameterized(file("src/tests/data/words.toml", name = "id"))]
+fn data_file(word: String, #[rest] letters: Vec<char>) {
+    assert_eq!(word.chars().collect::<Vec<_>>(), letters);
+}
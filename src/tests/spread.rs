@@ -0,0 +1,105 @@
+use crate::parameterized;
+
+const RFC_EXAMPLE_1: (&str, u32, bool) = ("yare", 4, true);
+const RFC_EXAMPLE_2: (&str, u32, bool) = ("⛵", 3, false);
+
+#[parameterized(
+    rfc1 = { ..RFC_EXAMPLE_1 },
+    rfc2 = { ..RFC_EXAMPLE_2 },
+    inline = { "", 0, true },
+)]
+fn whole_tuple(text: &str, length: u32, ascii: bool) {
+    assert_eq!(text.len() as u32, length);
+    assert_eq!(text.is_ascii(), ascii);
+}
+
+const PAIR: (u32, u32) = (2, 3);
+
+#[parameterized(
+    first = { ..PAIR, 5 },
+    last = { 5, ..PAIR },
+)]
+fn with_other_arguments(a: u32, b: u32, c: u32) {
+    assert!(a + b == c || a == b + c);
+}
+
+#[parameterized(
+    middle = { 1, ..(2, 3), 4 },
+)]
+fn in_the_middle(a: u32, b: u32, c: u32, d: u32) {
+    assert_eq!((a, b, c, d), (1, 2, 3, 4));
+}
+
+#[parameterized(
+    pair = { ..PAIR },
+)]
+fn case_info(a: u32, b: u32) {
+    assert_eq!((a, b), (2, 3));
+    assert_eq!(yare::case!().args, &[("a", "PAIR.0"), ("b", "PAIR.1")]);
+}
+
+fn tuple() -> (String, usize) {
+    ("yare".to_string(), 4)
+}
+
+#[parameterized(
+    function = { ..tuple() },
+)]
+fn expression(text: String, length: usize) {
+    assert_eq!(text.len(), length);
+}
+
+#[parameterized(
+    range_to = { { ..5 } },
+)]
+fn parenthesized_range(range: std::ops::RangeTo<u32>) {
+    assert_eq!(range.end, 5);
+}
+
+// With a #[rest] parameter, `..5` is a range, and not a spread
+#[parameterized(
+    range_to = { ..5 },
+    with_other_arguments = { ..5, 5 },
+)]
+fn range_with_rest(range: std::ops::RangeTo<u32>, #[rest] ends: Vec<u32>) {
+    assert!(ends.iter().all(|end| *end == range.end));
+}
+
+// With an argument for each parameter, `..1` is a range, and not a spread
+#[parameterized(
+    one = { ..1, 1 },
+    two = { ..2, 2 },
+)]
+fn range_and_end(range: std::ops::RangeTo<u32>, end: u32) {
+    assert_eq!(range.end, end);
+}
+
+#[derive(crate::Spread)]
+struct Example {
+    text: &'static str,
+    length: u32,
+}
+
+const STRUCT_EXAMPLE: Example = Example {
+    text: "yare",
+    length: 4,
+};
+
+#[derive(crate::Spread)]
+struct Pair(u32, u32);
+
+#[parameterized(
+    named_fields = { ..STRUCT_EXAMPLE, true },
+    literal = { ..Example { text: "⛵", length: 3 }, false },
+)]
+fn struct_with_named_fields(text: &str, length: u32, ascii: bool) {
+    assert_eq!(text.len() as u32, length);
+    assert_eq!(text.is_ascii(), ascii);
+}
+
+#[parameterized(
+    tuple_struct = { 5, ..Pair(2, 3) },
+)]
+fn tuple_struct(a: u32, b: u32, c: u32) {
+    assert_eq!(a, b + c);
+}
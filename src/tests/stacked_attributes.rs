@@ -0,0 +1,29 @@
+use yare::parameterized;
+
+yare::cases! {
+    EVEN = {
+        two = { 2 },
+        four = { 4 },
+    }
+}
+
+#[parameterized(
+    zero = { 0 },
+)]
+#[parameterized(use EVEN)]
+#[cfg_attr(unix, parameterized(unix_only = { 6 }))]
+#[cfg_attr(not(unix), parameterized(not_unix = { 8 }))]
+fn is_even(value: u32) {
+    assert_eq!(value % 2, 0);
+}
+
+#[parameterized(
+    one = { 1 },
+)]
+#[yare::parameterized(
+    ten = { 10 },
+)]
+#[should_panic]
+fn panics(value: u32) {
+    assert!(value > 100);
+}
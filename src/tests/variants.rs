@@ -0,0 +1,94 @@
+use crate::{parameterized, Variants};
+
+#[derive(Clone, Copy, Debug, PartialEq, Variants)]
+enum Fruit {
+    Apple,
+    BlackBerry,
+    Pear,
+}
+
+#[derive(Debug, PartialEq, Variants)]
+pub enum Status {
+    HttpOK,
+    NotFound,
+}
+
+// Public enums with the same name in different modules
+mod land {
+    #[derive(Debug, PartialEq, crate::Variants)]
+    pub enum Kind {
+        Car,
+        Bike,
+    }
+}
+
+mod water {
+    #[derive(Debug, PartialEq, crate::Variants)]
+    pub enum Kind {
+        Boat,
+    }
+}
+
+#[test]
+fn lists_the_variants() {
+    assert_eq!(
+        Fruit::VARIANTS,
+        &[Fruit::Apple, Fruit::BlackBerry, Fruit::Pear]
+    );
+}
+
+#[parameterized(variants = Fruit)]
+fn each_variant(fruit: Fruit) {
+    let expected = match yare::case!().name {
+        "apple" => Fruit::Apple,
+        "black_berry" => Fruit::BlackBerry,
+        "pear" => Fruit::Pear,
+        name => panic!("unexpected test case `{}`", name),
+    };
+
+    assert_eq!(fruit, expected);
+}
+
+#[parameterized(variants = Status)]
+fn named_in_snake_case(status: Status) {
+    let expected = match status {
+        Status::HttpOK => "http_ok",
+        Status::NotFound => "not_found",
+    };
+
+    assert_eq!(yare::case!().name, expected);
+}
+
+#[parameterized(variants = crate::tests::variants::Fruit, lemon = { Fruit::Pear })]
+fn by_path_with_other_cases(fruit: Fruit) {
+    assert!(Fruit::VARIANTS.contains(&fruit));
+}
+
+#[parameterized(variants = Fruit {
+    Apple => "apple",
+    BlackBerry => "blackberry",
+    Pear => "pear",
+})]
+fn mapping(fruit: Fruit, name: &str) {
+    assert_eq!(format!("{:?}", fruit).to_lowercase(), name);
+}
+
+#[parameterized(variants = Fruit {
+    Apple => { 1, true },
+    BlackBerry => { 20, false },
+    Pear => { 1, true },
+})]
+fn mapping_to_several_arguments(fruit: Fruit, count: u32, grows_on_trees: bool) {
+    assert_eq!(count == 1, grows_on_trees);
+    assert_eq!(fruit == Fruit::BlackBerry, !grows_on_trees);
+}
+
+#[parameterized(variants = land::Kind)]
+fn same_name_in_other_modules(kind: land::Kind) {
+    assert_ne!(format!("{:?}", kind), format!("{:?}", water::Kind::Boat));
+}
+
+#[parameterized(variants = water::Kind)]
+fn same_name_in_another_module(kind: water::Kind) {
+    assert_eq!(kind, water::Kind::Boat);
+}
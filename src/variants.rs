@@ -0,0 +1,28 @@
+/// The variants of a fieldless enum.
+///
+/// This trait is implemented with `#[derive(yare::Variants)]`, which also makes the variants
+/// available to the `#[parameterized(...)]` attribute: `variants = Fruit` generates a test case
+/// for each variant of `Fruit`, named after the variant, e.g. `apple` for `Fruit::Apple`.
+///
+/// **Example**
+///
+/// ```rust
+/// use yare::{parameterized, Variants};
+///
+/// #[derive(Debug, PartialEq, Variants)]
+/// enum Fruit {
+///     Apple,
+///     Pear,
+/// }
+///
+/// assert_eq!(Fruit::VARIANTS, &[Fruit::Apple, Fruit::Pear]);
+///
+/// #[parameterized(variants = Fruit)]
+/// fn is_listed(fruit: Fruit) {
+///     assert!(Fruit::VARIANTS.contains(&fruit));
+/// }
+/// ```
+pub trait Variants: Sized + 'static {
+    /// All variants of the enum, in the order in which they are declared
+    const VARIANTS: &'static [Self];
+}
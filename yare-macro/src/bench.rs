@@ -0,0 +1,111 @@
+use crate::diagnostics::Diagnostics;
+use crate::test_fn::TestFn;
+use syn::spanned::Spanned;
+
+/// Checks whether the test cases of the test function can be benchmarked, as is done with the
+/// `bench` option: criterion runs the test function from a closure, many times over.
+pub fn check(
+    test_fn: &TestFn,
+    option: &::syn::Ident,
+    other_options: &[Option<&::syn::Ident>],
+) -> ::syn::Result<()> {
+    let mut diagnostics = Diagnostics::default();
+
+    for other in other_options.iter().flatten() {
+        diagnostics.push(::syn::Error::new(
+            other.span(),
+            format_args!(
+                "The `{}` option can't be used together with the `{}` option",
+                other, option
+            ),
+        ));
+    }
+
+    if let Some(asyncness) = test_fn.asyncness() {
+        diagnostics.push(::syn::Error::new(
+            asyncness.span(),
+            "The `bench` option can't benchmark async test functions; make the test function synchronous",
+        ));
+    }
+
+    if let Some(unsafety) = test_fn.unsafety() {
+        diagnostics.push(::syn::Error::new(
+            unsafety.span(),
+            "The `bench` option can't benchmark unsafe test functions; use an `unsafe` block in the body instead",
+        ));
+    }
+
+    if let Some(attribute) = test_fn
+        .attributes()
+        .iter()
+        .find(|attribute| attribute.path().is_ident("should_panic"))
+    {
+        diagnostics.push(::syn::Error::new(
+            attribute.path().span(),
+            "The `bench` option can't be used together with #[should_panic], since a benchmark must not panic",
+        ));
+    }
+
+    diagnostics.finish(())
+}
+
+/// A criterion benchmark function for a test case, named after the test case, with the benchmark
+/// id `test_fn/case`.
+///
+/// The arguments are bound in the setup of each iteration, so only the body of the test function
+/// is measured. They're passed through `black_box`, so the compiler can't optimize the body for
+/// the specific arguments.
+pub fn function(
+    test_fn: &TestFn,
+    id: &::syn::Ident,
+    parameters: &[(&::syn::Ident, &::syn::Type)],
+    case_info: ::proc_macro2::TokenStream,
+    bindings: ::proc_macro2::TokenStream,
+    body: ::proc_macro2::TokenStream,
+) -> ::proc_macro2::TokenStream {
+    let test_fn_ident = test_fn.identifier();
+    let idents = parameters
+        .iter()
+        .map(|(ident, _)| ident)
+        .collect::<Vec<_>>();
+    // Without parameters, the setup returns `()` implicitly
+    let arguments = match idents.is_empty() {
+        true => None,
+        false => Some(::quote::quote! { (#(#idents,)*) }),
+    };
+    let output = match test_fn.return_type() {
+        ::syn::ReturnType::Default => None,
+        ::syn::ReturnType::Type(arrow, typ) => Some(::quote::quote! { #arrow #typ }),
+    };
+
+    ::quote::quote! {
+        pub fn #id(criterion: &mut ::yare::criterion::Criterion) {
+            #case_info
+
+            criterion.bench_function(
+                ::core::concat!(::core::stringify!(#test_fn_ident), "/", ::core::stringify!(#id)),
+                |bencher| {
+                    bencher.iter_batched(
+                        || {
+                            #bindings
+                            #arguments
+                        },
+                        |(#(#idents,)*)| #output {
+                            let (#(#idents,)*) = ::yare::criterion::black_box((#(#idents,)*));
+                            #body
+                        },
+                        ::yare::criterion::BatchSize::SmallInput,
+                    )
+                },
+            );
+        }
+    }
+}
+
+/// A `benches()` function, which runs the benchmark of each test case, as defined by
+/// `criterion_group!`, so it can be passed to `criterion_main!`.
+pub fn group(ids: &[&::syn::Ident]) -> ::proc_macro2::TokenStream {
+    ::quote::quote! {
+        ::yare::criterion::criterion_group!(benches, #(#ids),*);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::diagnostics::Diagnostics;
+use crate::test_cases::TestCase;
+use quote::{format_ident, ToTokens};
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+
+/// Test cases for the boundary values of a numeric parameter.
+/// The syntax looks like this `boundaries(n)`, or like this `boundaries(n, radix = 10)` when the
+/// test function has other parameters, which then take the given arguments in each test case.
+///
+/// The test cases are named after the parameter and the boundary value, e.g. `n_min` or `n_max`.
+#[derive(Clone)]
+pub struct Boundaries {
+    keyword: ::syn::Ident,
+    parens: ::syn::token::Paren,
+    arguments: Punctuated<Argument, Token![,]>,
+}
+
+/// Either the parameter for which boundary values are generated, e.g. `n`, or the argument for
+/// one of the other parameters, e.g. `radix = 10`.
+#[derive(Clone)]
+struct Argument {
+    parameter: ::syn::Ident,
+    value: Option<(Token![=], ::syn::Expr)>,
+}
+
+impl Boundaries {
+    /// Whether the input starts with `boundaries(`.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        match fork.parse::<::syn::Ident>() {
+            Ok(keyword) => keyword == "boundaries" && fork.peek(::syn::token::Paren),
+            Err(_) => false,
+        }
+    }
+
+    /// A test case for each boundary value of the type of the parameter.
+    pub fn test_cases(
+        &self,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+    ) -> Result<Vec<TestCase>> {
+        let mut diagnostics = Diagnostics::default();
+
+        // Parsing guarantees there's exactly one parameter without a value
+        let boundary = self
+            .arguments
+            .iter()
+            .find(|argument| argument.value.is_none())
+            .map(|argument| &argument.parameter)
+            .expect("a parameter for the boundary values");
+
+        for argument in self.arguments.iter() {
+            if argument.value.is_some() && argument.parameter == *boundary {
+                diagnostics.push(::syn::Error::new(
+                    argument.parameter.span(),
+                    format_args!(
+                        "Parameter `{}` takes the boundary values, so it can't take an argument too",
+                        boundary
+                    ),
+                ));
+            } else if !parameters
+                .iter()
+                .any(|(ident, _)| *ident == &argument.parameter)
+            {
+                diagnostics.push(::syn::Error::new(
+                    argument.parameter.span(),
+                    format_args!("Unknown parameter `{}`", argument.parameter),
+                ));
+            }
+        }
+
+        for (ident, _) in parameters {
+            if !self
+                .arguments
+                .iter()
+                .any(|argument| argument.parameter == **ident)
+            {
+                diagnostics.push(::syn::Error::new(
+                    self.parens.span.close(),
+                    format_args!(
+                        "Missing an argument for parameter `{}`, e.g. `{} = ...`",
+                        ident, ident
+                    ),
+                ));
+            }
+        }
+
+        let values = parameters
+            .iter()
+            .find(|(ident, _)| *ident == boundary)
+            .and_then(|(_, typ)| {
+                let values = Number::of(typ).map(|number| number.boundary_values(typ));
+
+                if values.is_none() {
+                    diagnostics.push(::syn::Error::new(
+                        boundary.span(),
+                        format_args!(
+                            "Unsupported type `{}` for parameter `{}`; expected a primitive integer or float type, e.g. `i32` or `f64`",
+                            typ.to_token_stream(),
+                            boundary
+                        ),
+                    ));
+                }
+
+                values
+            });
+
+        let values = diagnostics.finish(values.unwrap_or_default())?;
+
+        let span = boundary.span();
+
+        Ok(values
+            .into_iter()
+            .map(|(name, value)| {
+                let arguments = parameters
+                    .iter()
+                    .map(|(ident, _)| {
+                        if *ident == boundary {
+                            value.clone()
+                        } else {
+                            self.arguments
+                                .iter()
+                                .find_map(|argument| match &argument.value {
+                                    Some((_, expr)) if argument.parameter == **ident => {
+                                        Some(expr.clone())
+                                    }
+                                    _ => None,
+                                })
+                                .expect("an argument for every parameter")
+                        }
+                    })
+                    .collect();
+
+                TestCase::generated(
+                    format_ident!("{}_{}", boundary, name, span = span),
+                    arguments,
+                )
+            })
+            .collect())
+    }
+}
+
+impl Parse for Boundaries {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+
+        let boundaries = Boundaries {
+            keyword: input.parse()?,
+            parens: parenthesized!(content in input),
+            arguments: Punctuated::parse_terminated(&content)?,
+        };
+
+        let mut without_value = boundaries
+            .arguments
+            .iter()
+            .filter(|argument| argument.value.is_none());
+
+        match (without_value.next(), without_value.next()) {
+            (Some(_), None) => Ok(boundaries),
+            (None, _) => Err(::syn::Error::new(
+                boundaries.parens.span.join(),
+                "Expected the parameter for the boundary values, e.g. `boundaries(n)`",
+            )),
+            (Some(_), Some(second)) => Err(::syn::Error::new(
+                second.parameter.span(),
+                format_args!(
+                    "Expected a single parameter for the boundary values; other parameters take an argument, e.g. `{} = ...`",
+                    second.parameter
+                ),
+            )),
+        }
+    }
+}
+
+impl ToTokens for Boundaries {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.keyword.to_tokens(tokens);
+        self.parens
+            .surround(tokens, |tokens| self.arguments.to_tokens(tokens));
+    }
+}
+
+impl Parse for Argument {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let parameter = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            Some((input.parse()?, input.parse()?))
+        } else {
+            None
+        };
+
+        Ok(Argument { parameter, value })
+    }
+}
+
+impl ToTokens for Argument {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.parameter.to_tokens(tokens);
+
+        if let Some((assignment, value)) = &self.value {
+            assignment.to_tokens(tokens);
+            value.to_tokens(tokens);
+        }
+    }
+}
+
+/// The kinds of numeric types for which boundary values can be generated.
+enum Number {
+    Unsigned,
+    Signed,
+    Float,
+}
+
+impl Number {
+    fn of(typ: &::syn::Type) -> Option<Number> {
+        let ident = match typ {
+            ::syn::Type::Path(path) if path.qself.is_none() => path.path.get_ident()?,
+            _ => return None,
+        };
+
+        match ident.to_string().as_str() {
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => Some(Number::Unsigned),
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => Some(Number::Signed),
+            "f32" | "f64" => Some(Number::Float),
+            _ => None,
+        }
+    }
+
+    /// The boundary values of the given type, with the name of each value.
+    fn boundary_values(&self, typ: &::syn::Type) -> Vec<(&'static str, ::syn::Expr)> {
+        let mut values: Vec<(&'static str, ::syn::Expr)> = vec![
+            ("min", ::syn::parse_quote! { <#typ>::MIN }),
+            ("max", ::syn::parse_quote! { <#typ>::MAX }),
+        ];
+
+        match self {
+            Number::Unsigned | Number::Signed => {
+                // The minimum of an unsigned type is zero already
+                if let Number::Signed = self {
+                    values.push(("zero", ::syn::parse_quote! { 0 }));
+                }
+
+                values.push(("one", ::syn::parse_quote! { 1 }));
+
+                if let Number::Signed = self {
+                    values.push(("minus_one", ::syn::parse_quote! { -1 }));
+                }
+
+                values.push(("max_minus_one", ::syn::parse_quote! { <#typ>::MAX - 1 }));
+            }
+            Number::Float => {
+                values.push(("zero", ::syn::parse_quote! { 0.0 }));
+                values.push(("negative_zero", ::syn::parse_quote! { -0.0 }));
+                values.push(("one", ::syn::parse_quote! { 1.0 }));
+                values.push(("minus_one", ::syn::parse_quote! { -1.0 }));
+                values.push(("epsilon", ::syn::parse_quote! { <#typ>::EPSILON }));
+                values.push(("infinity", ::syn::parse_quote! { <#typ>::INFINITY }));
+                values.push(("neg_infinity", ::syn::parse_quote! { <#typ>::NEG_INFINITY }));
+                values.push(("nan", ::syn::parse_quote! { <#typ>::NAN }));
+            }
+        }
+
+        values
+    }
+}
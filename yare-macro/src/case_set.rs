@@ -0,0 +1,293 @@
+use crate::test_cases::TestCases;
+use quote::{format_ident, ToTokens};
+use std::fmt::Formatter;
+use syn::braced;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+
+/// A list of named case sets, as defined with `yare::cases! { ... }`.
+/// The syntax for a case set looks like this `pub NAME = { id = { arg1, ..., argn }, ... }`.
+pub struct CaseSets {
+    sets: Punctuated<CaseSet, Token![,]>,
+}
+
+impl CaseSets {
+    pub fn to_token_stream(&self) -> ::proc_macro2::TokenStream {
+        let sets = self.sets.iter().map(CaseSet::to_token_stream);
+
+        ::quote::quote! {
+            #(#sets)*
+        }
+    }
+}
+
+impl Parse for CaseSets {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(CaseSets {
+            sets: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+/// A single named case set.
+///
+/// Since an attribute macro can't look at other items, a case set is defined as a `macro_rules!`
+/// macro, which is invoked by the `parameterized` attribute when it references the case set. The
+/// macro then calls back into yare with the test cases of the set.
+struct CaseSet {
+    visibility: ::syn::Visibility,
+    name: ::syn::Ident,
+    cases: ::proc_macro2::TokenStream,
+}
+
+impl CaseSet {
+    fn to_token_stream(&self) -> ::proc_macro2::TokenStream {
+        let visibility = &self.visibility;
+        let name = &self.name;
+        let cases = &self.cases;
+        let macro_name = exported_name("__yare_cases", name, cases);
+
+        // Only public case sets are exported, so they can be used from other crates
+        let export = match visibility {
+            ::syn::Visibility::Public(_) => Some(::quote::quote! { #[macro_export] }),
+            _ => None,
+        };
+
+        ::quote::quote! {
+            #[doc(hidden)]
+            #export
+            macro_rules! #macro_name {
+                ($($state:tt)*) => {
+                    ::yare::__resolve_cases! { { #cases } $($state)* }
+                };
+            }
+
+            #[doc(inline)]
+            #visibility use #macro_name as #name;
+        }
+    }
+}
+
+impl Parse for CaseSet {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let visibility = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+
+        let content;
+        braced!(content in input);
+        let cases = content.parse::<::proc_macro2::TokenStream>()?;
+
+        // Report syntax errors where the case set is defined, instead of where it is used
+        ::syn::parse2::<TestCases>(cases.clone())?;
+
+        Ok(CaseSet {
+            visibility,
+            name,
+            cases,
+        })
+    }
+}
+
+/// The name of the `macro_rules!` macro behind a case set, or behind the variants of an enum.
+///
+/// An exported macro ends up in the root of the crate, so public case sets with the same name in
+/// different modules would clash. The name is therefore mangled with a hash of where and how the
+/// case set is defined; users only refer to it through the `use` which renames it.
+pub fn exported_name(
+    prefix: &str,
+    name: &::syn::Ident,
+    definition: &::proc_macro2::TokenStream,
+) -> ::syn::Ident {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    // The span includes the position of the name in the sources of the crate
+    format!("{:?}", name.span()).hash(&mut hasher);
+    definition.to_string().hash(&mut hasher);
+
+    format_ident!("{}_{}_{:016x}", prefix, name, hasher.finish())
+}
+
+/// A reference to a named case set, which looks like `use NAME` or `use path::to::NAME`, or to
+/// the variants of an enum which derives `yare::Variants`, which looks like `variants = Fruit`.
+#[derive(Clone)]
+pub struct CaseSetReference {
+    kind: ReferenceKind,
+    path: ::syn::Path,
+}
+
+#[derive(Clone)]
+enum ReferenceKind {
+    CaseSet(Token![use]),
+    Variants(::syn::Ident, Token![=]),
+}
+
+impl CaseSetReference {
+    /// Whether the input starts with a reference to the variants of an enum, and not with the
+    /// variants of an enum and their arguments, e.g. `variants = Fruit { ... }`.
+    pub fn peek_variants(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        match fork.parse::<::syn::Ident>() {
+            Ok(keyword) if keyword == "variants" && fork.peek(Token![=]) => {
+                fork.parse::<Token![=]>().is_ok()
+                    && fork.parse::<::syn::Path>().is_ok()
+                    && (fork.is_empty() || fork.peek(Token![,]))
+            }
+            _ => false,
+        }
+    }
+
+    /// The path of the referenced case set or enum, as written in the attribute.
+    pub fn path(&self) -> &::syn::Path {
+        &self.path
+    }
+
+    /// Whether this references the variants of an enum, rather than a case set.
+    pub fn is_variants(&self) -> bool {
+        matches!(self.kind, ReferenceKind::Variants(..))
+    }
+
+    /// Invokes the macro which defines the case set, with everything needed to continue the
+    /// expansion of the `parameterized` attribute, once the test cases of the set are known.
+    pub fn to_callback(
+        &self,
+        args: &::proc_macro2::TokenStream,
+        item: &::proc_macro2::TokenStream,
+    ) -> ::proc_macro2::TokenStream {
+        let path = &self.path;
+
+        ::quote::quote! {
+            #path! { { #args } #item }
+        }
+    }
+}
+
+impl std::fmt::Debug for CaseSetReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!(
+            "CaseSetReference(path = {})",
+            self.path.to_token_stream()
+        ))
+    }
+}
+
+impl Parse for CaseSetReference {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let kind = if input.peek(Token![use]) {
+            ReferenceKind::CaseSet(input.parse()?)
+        } else {
+            ReferenceKind::Variants(input.parse()?, input.parse()?)
+        };
+
+        Ok(CaseSetReference {
+            kind,
+            path: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for CaseSetReference {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        match &self.kind {
+            ReferenceKind::CaseSet(use_token) => use_token.to_tokens(tokens),
+            ReferenceKind::Variants(keyword, assignment) => {
+                keyword.to_tokens(tokens);
+                assignment.to_tokens(tokens);
+            }
+        }
+
+        self.path.to_tokens(tokens);
+    }
+}
+
+/// The input given to `__resolve_cases!` by a case set macro: the test cases of the set, followed
+/// by the arguments of the `parameterized` attribute and the item it was placed on.
+pub struct Resolution {
+    cases: ::proc_macro2::TokenStream,
+    args: ::proc_macro2::TokenStream,
+    item: ::proc_macro2::TokenStream,
+}
+
+impl Resolution {
+    /// The attribute arguments and the item, where the first referenced case set has been replaced
+    /// with its test cases. Case sets referenced by the attribute arguments are resolved first,
+    /// followed by those of additional `#[parameterized(...)]` attributes on the item.
+    pub fn resolve(self) -> Result<(::proc_macro2::TokenStream, ::proc_macro2::TokenStream)> {
+        let test_cases = ::syn::parse2::<TestCases>(self.args)?;
+        let cases = ::syn::parse2::<TestCases>(self.cases)?;
+
+        if test_cases.first_case_set().is_some() {
+            let resolved = test_cases.resolve_first_case_set(cases);
+
+            return Ok((resolved.into_token_stream(), self.item));
+        }
+
+        let mut cases = Some(cases);
+
+        let mut item = ::syn::parse2::<::syn::Item>(self.item)?;
+
+        match &mut item {
+            ::syn::Item::Fn(fun) => resolve_in_attributes(&mut fun.attrs, &mut cases)?,
+            ::syn::Item::Mod(item_mod) => {
+                resolve_in_attributes(&mut item_mod.attrs, &mut cases)?;
+
+                for inner in item_mod.content.iter_mut().flat_map(|(_, items)| items) {
+                    if let ::syn::Item::Fn(fun) = inner {
+                        resolve_in_attributes(&mut fun.attrs, &mut cases)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok((test_cases.into_token_stream(), item.into_token_stream()))
+    }
+}
+
+/// Replaces the first referenced case set of a `#[parameterized(...)]` attribute, if the test
+/// cases have not been used yet.
+fn resolve_in_attributes(
+    attributes: &mut [::syn::Attribute],
+    cases: &mut Option<TestCases>,
+) -> Result<()> {
+    for attribute in attributes.iter_mut() {
+        if cases.is_none() {
+            break;
+        }
+
+        if !crate::test_fn::is_parameterized(attribute.path())
+            || matches!(attribute.meta, ::syn::Meta::Path(_))
+        {
+            continue;
+        }
+
+        let test_cases = attribute.parse_args::<TestCases>()?;
+
+        if test_cases.first_case_set().is_none() {
+            continue;
+        }
+
+        if let (::syn::Meta::List(list), Some(cases)) = (&mut attribute.meta, cases.take()) {
+            list.tokens = test_cases.resolve_first_case_set(cases).into_token_stream();
+        }
+    }
+
+    Ok(())
+}
+
+impl Parse for Resolution {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let cases;
+        braced!(cases in input);
+        let args;
+        braced!(args in input);
+
+        Ok(Resolution {
+            cases: cases.parse()?,
+            args: args.parse()?,
+            item: input.parse()?,
+        })
+    }
+}
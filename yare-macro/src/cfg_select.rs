@@ -0,0 +1,220 @@
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+/// An argument with an alternative per configuration, e.g.
+/// `cfg_select { unix => "/", not(unix) => "\\" }`. The first alternative of which the
+/// configuration predicate holds is selected, and `_ => ...` selects a fallback.
+///
+/// Since this isn't a Rust expression, it's carried in the arguments of a test case as
+/// `syn::Expr::Verbatim`, and parsed again when the argument is bound to its parameter.
+pub struct CfgSelect {
+    keyword: ::syn::Ident,
+    braces: ::syn::token::Brace,
+    arms: Punctuated<Arm, Token![,]>,
+}
+
+struct Arm {
+    predicate: Predicate,
+    arrow: Token![=>],
+    value: ::syn::Expr,
+}
+
+enum Predicate {
+    Cfg(Box<::syn::Meta>),
+    Fallback(Token![_]),
+}
+
+/// Parses an argument of a test case, which is either an expression or a `cfg_select { ... }`.
+pub fn parse_argument(input: ParseStream) -> Result<::syn::Expr> {
+    if CfgSelect::peek(input) {
+        let select = input.parse::<CfgSelect>()?;
+
+        Ok(::syn::Expr::Verbatim(select.into_token_stream()))
+    } else {
+        input.parse()
+    }
+}
+
+impl CfgSelect {
+    /// Whether the input starts with `cfg_select {`.
+    fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        match fork.parse::<::syn::Ident>() {
+            Ok(keyword) => keyword == "cfg_select" && fork.peek(::syn::token::Brace),
+            Err(_) => false,
+        }
+    }
+
+    /// The `cfg_select { ... }` of an argument, if it is one.
+    pub fn from_argument(argument: &::proc_macro2::TokenStream) -> Option<CfgSelect> {
+        ::syn::parse2::<CfgSelect>(argument.clone()).ok()
+    }
+
+    /// A binding per alternative, each gated by the configuration in which it's selected. The
+    /// given function creates the binding for the value of an alternative.
+    pub fn bindings(
+        &self,
+        binding: impl Fn(&::syn::Expr) -> ::proc_macro2::TokenStream,
+    ) -> ::proc_macro2::TokenStream {
+        let mut previous = Vec::new();
+
+        let bindings = self.arms.iter().map(|arm| {
+            // An alternative is only selected when none of the alternatives before it is
+            let cfg = match (&arm.predicate, previous.is_empty()) {
+                (Predicate::Fallback(_), true) => None,
+                (Predicate::Fallback(_), false) => {
+                    Some(::quote::quote! { not(any(#(#previous),*)) })
+                }
+                (Predicate::Cfg(meta), true) => Some(meta.to_token_stream()),
+                (Predicate::Cfg(meta), false) => {
+                    Some(::quote::quote! { all(#meta, not(any(#(#previous),*))) })
+                }
+            };
+
+            if let Predicate::Cfg(meta) = &arm.predicate {
+                previous.push(meta);
+            }
+
+            let cfg = cfg.map(|cfg| ::quote::quote! { #[cfg(#cfg)] });
+            let binding = binding(&arm.value);
+
+            ::quote::quote! {
+                #cfg
+                #binding
+            }
+        });
+
+        let bindings = bindings.collect::<Vec<_>>();
+
+        ::quote::quote! {
+            #(#bindings)*
+        }
+    }
+
+    /// Whether an alternative is selected in every configuration: when there's a fallback, an
+    /// alternative for `all()`, or alternatives for both a predicate and its negation.
+    fn is_exhaustive(&self) -> bool {
+        let predicates = self
+            .arms
+            .iter()
+            .filter_map(|arm| match &arm.predicate {
+                Predicate::Cfg(meta) => Some(meta.as_ref()),
+                Predicate::Fallback(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        if predicates.len() < self.arms.len() {
+            return true;
+        }
+
+        let always = |meta: &&::syn::Meta| match meta {
+            ::syn::Meta::List(list) => list.path.is_ident("all") && list.tokens.is_empty(),
+            _ => false,
+        };
+
+        let negated = |meta: &::syn::Meta| match meta {
+            ::syn::Meta::List(list) if list.path.is_ident("not") => Some(list.tokens.to_string()),
+            _ => None,
+        };
+
+        predicates.iter().any(always)
+            || predicates.iter().any(|meta| {
+                negated(meta).map_or(false, |inner| {
+                    predicates
+                        .iter()
+                        .any(|other| other.to_token_stream().to_string() == inner)
+                })
+            })
+    }
+}
+
+impl Parse for CfgSelect {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword = input.parse::<::syn::Ident>()?;
+
+        if keyword != "cfg_select" {
+            return Err(::syn::Error::new(keyword.span(), "Expected `cfg_select`"));
+        }
+
+        let content;
+        let select = CfgSelect {
+            keyword,
+            braces: braced!(content in input),
+            arms: Punctuated::parse_terminated(&content)?,
+        };
+
+        if select.arms.is_empty() {
+            return Err(::syn::Error::new(
+                select.braces.span.join(),
+                "Expected an alternative per configuration, e.g. `cfg_select { unix => \"/\", _ => \"\\\\\" }`",
+            ));
+        }
+
+        let fallback = select
+            .arms
+            .iter()
+            .position(|arm| matches!(arm.predicate, Predicate::Fallback(_)));
+
+        if let Some(position) = fallback {
+            if let Some(arm) = select.arms.iter().nth(position + 1) {
+                return Err(::syn::Error::new(
+                    arm.predicate.span(),
+                    "Unreachable alternative; the fallback `_ => ...` must be the last alternative",
+                ));
+            }
+        }
+
+        if !select.is_exhaustive() {
+            return Err(::syn::Error::new(
+                select.keyword.span(),
+                "The alternatives may not cover every configuration; add a fallback alternative, e.g. `_ => ...`",
+            ));
+        }
+
+        Ok(select)
+    }
+}
+
+impl ToTokens for CfgSelect {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.keyword.to_tokens(tokens);
+        self.braces
+            .surround(tokens, |tokens| self.arms.to_tokens(tokens));
+    }
+}
+
+impl Parse for Arm {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let predicate = if input.peek(Token![_]) {
+            Predicate::Fallback(input.parse()?)
+        } else {
+            Predicate::Cfg(Box::new(input.parse()?))
+        };
+
+        Ok(Arm {
+            predicate,
+            arrow: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Arm {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.predicate.to_tokens(tokens);
+        self.arrow.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+    }
+}
+
+impl ToTokens for Predicate {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        match self {
+            Predicate::Cfg(meta) => meta.to_tokens(tokens),
+            Predicate::Fallback(underscore) => underscore.to_tokens(tokens),
+        }
+    }
+}
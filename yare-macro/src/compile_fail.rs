@@ -0,0 +1,69 @@
+use crate::diagnostics::Diagnostics;
+use crate::test_fn::TestFn;
+use syn::spanned::Spanned;
+
+/// Checks whether the test function can drive compile-fail test cases, as is done with the
+/// `compile_fail` option: it takes the source and the expected compiler output of each test case,
+/// and has an empty body, since yare supplies the body.
+fn check(
+    test_fn: &TestFn,
+    parameters: &[(&::syn::Ident, &::syn::Type)],
+    option: &::syn::Ident,
+) -> ::syn::Result<()> {
+    let mut diagnostics = Diagnostics::default();
+
+    if parameters.len() != 2 {
+        diagnostics.push(::syn::Error::new(
+            option.span(),
+            "The `compile_fail` option requires a test function with two parameters, for the source and the expected compiler output, e.g. `fn fails(source: &str, expected: &str) {}`",
+        ));
+    }
+
+    if !test_fn.body().stmts.is_empty() {
+        diagnostics.push(::syn::Error::new(
+            test_fn.body().span(),
+            "The body of a `compile_fail` test function isn't run, since yare compiles the source of each test case instead; leave it empty",
+        ));
+    }
+
+    if let ::syn::ReturnType::Type(_, typ) = test_fn.return_type() {
+        diagnostics.push(::syn::Error::new_spanned(
+            typ,
+            "The `compile_fail` option requires a test function without a return type",
+        ));
+    }
+
+    if let Some(asyncness) = test_fn.asyncness() {
+        diagnostics.push(::syn::Error::new(
+            asyncness.span(),
+            "The `compile_fail` option requires a synchronous test function",
+        ));
+    }
+
+    diagnostics.finish(())
+}
+
+/// The test function, with a body which checks with trybuild that the source fails to compile,
+/// with the expected output. It's spanned at the option, so a missing `trybuild` feature of yare
+/// is reported there.
+pub fn driver(
+    test_fn: &TestFn,
+    parameters: &[(&::syn::Ident, &::syn::Type)],
+    option: &::syn::Ident,
+) -> ::syn::Result<TestFn> {
+    check(test_fn, parameters, option)?;
+
+    let (source, expected) = (parameters[0].0, parameters[1].0);
+
+    let body = ::syn::parse_quote_spanned! { option.span()=>
+        {
+            ::yare::__private::compile_fail(
+                ::yare::case!(),
+                ::core::convert::AsRef::<str>::as_ref(&#source),
+                ::core::convert::AsRef::<str>::as_ref(&#expected),
+            )
+        }
+    };
+
+    Ok(test_fn.with_body(body))
+}
@@ -0,0 +1,55 @@
+use crate::diagnostics::Diagnostics;
+use crate::test_fn::TestFn;
+use syn::spanned::Spanned;
+
+/// Checks whether the test cases of the test function can be evaluated at compile time, as is
+/// done with the `const_eval` option: the test function must be a `const fn` without a return
+/// value, which is expected to succeed.
+pub fn check(test_fn: &TestFn, option: &::syn::Ident) -> ::syn::Result<()> {
+    let mut diagnostics = Diagnostics::default();
+
+    if test_fn.constness().is_none() {
+        diagnostics.push(::syn::Error::new(
+            option.span(),
+            "The `const_eval` option evaluates the test cases at compile time, which requires a `const fn` test function",
+        ));
+    }
+
+    if let ::syn::ReturnType::Type(_, typ) = test_fn.return_type() {
+        diagnostics.push(::syn::Error::new_spanned(
+            typ,
+            "The `const_eval` option requires a test function without a return type, since its outcome can't be checked at compile time",
+        ));
+    }
+
+    if let Some(attribute) = test_fn
+        .attributes()
+        .iter()
+        .find(|attribute| attribute.path().is_ident("should_panic"))
+    {
+        diagnostics.push(::syn::Error::new(
+            attribute.path().span(),
+            "The `const_eval` option can't be used together with #[should_panic], since a panicking test case fails the build",
+        ));
+    }
+
+    diagnostics.finish(())
+}
+
+/// A constant which evaluates the generated function of a test case, so the build fails when the
+/// test case panics. It's spanned at the id of the test case, so the error points at the test case.
+///
+/// Without a custom runner, the generated function is a `#[test]` fn, which only exists when
+/// compiling tests, so the constant is only evaluated then too.
+pub fn evaluation(id: &::syn::Ident, has_runner: bool) -> ::proc_macro2::TokenStream {
+    let cfg = if has_runner {
+        None
+    } else {
+        Some(::quote::quote! { #[cfg(test)] })
+    };
+
+    ::quote::quote_spanned! { id.span()=>
+        #cfg
+        const _: () = #id();
+    }
+}
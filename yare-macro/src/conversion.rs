@@ -0,0 +1,82 @@
+use syn::spanned::Spanned;
+
+/// How the argument of a parameter is converted to the type of the parameter, when the parameter
+/// is marked with `#[from_str]` or `#[into]`, e.g. `#[from_str] version: Version`.
+pub enum Conversion {
+    /// Parses the argument with `FromStr`, e.g. `"1.2.3"`
+    FromStr,
+    /// Converts the argument with `Into`, e.g. `"dir/file"` for a `PathBuf`
+    Into,
+}
+
+impl Conversion {
+    /// The conversion of each typed parameter, in order.
+    pub fn find(
+        inputs: &::syn::punctuated::Punctuated<::syn::FnArg, Token![,]>,
+    ) -> ::syn::Result<Vec<Option<Conversion>>> {
+        inputs
+            .iter()
+            .filter_map(|input| match input {
+                ::syn::FnArg::Typed(::syn::PatType { attrs, .. }) => Some(attrs),
+                ::syn::FnArg::Receiver(_) => None,
+            })
+            .map(|attrs| {
+                let mut conversions = attrs.iter().filter_map(|attr| {
+                    if attr.path().is_ident("from_str") {
+                        Some((attr, Conversion::FromStr))
+                    } else if attr.path().is_ident("into") {
+                        Some((attr, Conversion::Into))
+                    } else {
+                        None
+                    }
+                });
+
+                let (attribute, conversion) = match conversions.next() {
+                    Some(conversion) => conversion,
+                    None => return Ok(None),
+                };
+
+                if let Some((second, _)) = conversions.next() {
+                    return Err(::syn::Error::new(
+                        second.meta.span(),
+                        "Expected at most one of `#[from_str]` and `#[into]` per parameter",
+                    ));
+                }
+
+                if !matches!(attribute.meta, ::syn::Meta::Path(_)) {
+                    return Err(::syn::Error::new(
+                        attribute.meta.span(),
+                        "Expected `#[from_str]` or `#[into]`, without arguments",
+                    ));
+                }
+
+                if attrs.iter().any(|attr| attr.path().is_ident("rest")) {
+                    return Err(::syn::Error::new(
+                        attribute.meta.span(),
+                        "A `#[rest]` parameter can't be converted; convert its arguments instead",
+                    ));
+                }
+
+                Ok(Some(conversion))
+            })
+            .collect()
+    }
+
+    /// The expression which converts the argument of the given parameter. It's spanned at the type
+    /// of the parameter, so a type which can't be converted to is reported there.
+    pub fn convert(
+        &self,
+        parameter: &::syn::Ident,
+        typ: &::syn::Type,
+        argument: impl ::quote::ToTokens,
+    ) -> ::proc_macro2::TokenStream {
+        match self {
+            Conversion::FromStr => ::quote::quote_spanned! { typ.span()=>
+                ::yare::__private::from_str::<#typ, _>(::yare::case!(), ::core::stringify!(#parameter), #argument)
+            },
+            Conversion::Into => ::quote::quote_spanned! { typ.span()=>
+                ::core::convert::Into::<#typ>::into(#argument)
+            },
+        }
+    }
+}
@@ -0,0 +1,623 @@
+use crate::diagnostics::Diagnostics;
+use crate::test_cases::{Origin, TestCase};
+use quote::ToTokens;
+use std::path::PathBuf;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+
+mod csv;
+mod json;
+mod toml;
+
+/// Test cases which are loaded from a data file at compile time.
+/// The syntax looks like this `file = "tests/data/vectors.csv"`, or like this
+/// `file("tests/data/vectors.csv", name = "id")` to take the test case ids from another column.
+///
+/// Each record of the file becomes a test case. The fields of a record are mapped to the
+/// parameters of the test function by name, and the field of the name column holds the id of the
+/// test case.
+#[derive(Clone)]
+pub struct DataFile {
+    keyword: ::syn::Ident,
+    path: ::syn::LitStr,
+    name_column: Option<::syn::LitStr>,
+}
+
+impl DataFile {
+    /// Whether the input starts with a data file entry, and not with a test case named `file`.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        match fork.parse::<::syn::Ident>() {
+            Ok(keyword) if keyword == "file" => {
+                fork.peek(::syn::token::Paren)
+                    || (fork.peek(Token![=]) && fork.peek2(::syn::LitStr))
+            }
+            _ => false,
+        }
+    }
+
+    /// The test cases defined by the records of the data file.
+    pub fn test_cases(
+        &self,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+    ) -> Result<Vec<TestCase>> {
+        let path = self.path.value();
+        let source = std::fs::read_to_string(self.full_path()?).map_err(|error| {
+            ::syn::Error::new(
+                self.path.span(),
+                format_args!("Unable to read data file `{}`: {}", path, error),
+            )
+        })?;
+
+        let records = match path.rsplit('.').next() {
+            Some("csv") => csv::parse(&source),
+            Some("json") => json::parse(&source),
+            Some("toml") => toml::parse(&source),
+            _ => {
+                return Err(::syn::Error::new(
+                    self.path.span(),
+                    format_args!(
+                        "Unsupported data file `{}`; expected a `.csv`, `.json` or `.toml` file",
+                        path
+                    ),
+                ))
+            }
+        }
+        .map_err(|error| self.error(error.line, error.message))?;
+
+        let mut diagnostics = Diagnostics::default();
+
+        let test_cases = records
+            .iter()
+            .filter_map(|record| diagnostics.take(self.test_case(record, parameters)))
+            .collect();
+
+        diagnostics.finish(test_cases)
+    }
+
+    /// Includes the data file in the generated code, so the test cases are regenerated when the
+    /// file changes.
+    pub fn tracking(&self) -> Result<::proc_macro2::TokenStream> {
+        let full_path = self.full_path()?;
+        let full_path = full_path.to_string_lossy();
+
+        Ok(::quote::quote_spanned! { self.path.span()=>
+            const _: &[u8] = ::core::include_bytes!(#full_path);
+        })
+    }
+
+    fn full_path(&self) -> Result<PathBuf> {
+        manifest_dir(self.path.span()).map(|manifest_dir| manifest_dir.join(self.path.value()))
+    }
+
+    fn test_case(
+        &self,
+        record: &Record,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+    ) -> Result<TestCase> {
+        let span = self.path.span();
+        let name_column = self
+            .name_column
+            .as_ref()
+            .map_or_else(|| "name".to_string(), ::syn::LitStr::value);
+
+        let name = match record.field(&name_column) {
+            Some(Value::Text(name) | Value::String(name) | Value::Integer(name)) => name,
+            Some(_) => {
+                return Err(self.error(
+                    record.line,
+                    format!(
+                        "the `{}` field must hold the name of the test case",
+                        name_column
+                    ),
+                ))
+            }
+            None => {
+                return Err(self.error(
+                    record.line,
+                    format!(
+                        "missing the `{}` field with the name of the test case",
+                        name_column
+                    ),
+                ))
+            }
+        };
+
+        let id = identifier(name, span)
+            .map_err(|message| self.error(record.line, format!("{} `{}`", message, name)))?;
+
+        let mut arguments = Punctuated::new();
+        let mut sources = Vec::new();
+
+        for (ident, typ) in parameters {
+            let value = record.field(&ident.to_string()).ok_or_else(|| {
+                self.error(
+                    record.line,
+                    format!("missing a value for parameter `{}`", ident),
+                )
+            })?;
+
+            let expr = convert(value, typ, span).map_err(|message| {
+                self.error(
+                    record.line,
+                    format!(
+                        "{} for parameter `{}: {}`",
+                        message,
+                        ident,
+                        typ.to_token_stream()
+                    ),
+                )
+            })?;
+
+            arguments.push(::syn::parse2(expr)?);
+            sources.push(value.to_string());
+        }
+
+        Ok(TestCase::new(
+            id,
+            arguments,
+            Origin {
+                file: self.path.value(),
+                line: record.line,
+                sources,
+            },
+        ))
+    }
+
+    /// An error in the data file, which names the file and line.
+    fn error(&self, line: usize, message: impl std::fmt::Display) -> ::syn::Error {
+        ::syn::Error::new(
+            self.path.span(),
+            format_args!("{}:{}: {}", self.path.value(), line, message),
+        )
+    }
+}
+
+impl Parse for DataFile {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword = input.parse::<::syn::Ident>()?;
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+
+            return Ok(DataFile {
+                keyword,
+                path: input.parse()?,
+                name_column: None,
+            });
+        }
+
+        let content;
+        parenthesized!(content in input);
+
+        let path = content.parse()?;
+        let mut name_column = None;
+
+        if content.parse::<Option<Token![,]>>()?.is_some() && !content.is_empty() {
+            let option = content.parse::<::syn::Ident>()?;
+
+            if option != "name" {
+                return Err(::syn::Error::new(
+                    option.span(),
+                    "expected `name = \"column\"`, to select the column with the test case ids",
+                ));
+            }
+
+            content.parse::<Token![=]>()?;
+            name_column = Some(content.parse()?);
+            content.parse::<Option<Token![,]>>()?;
+        }
+
+        Ok(DataFile {
+            keyword,
+            path,
+            name_column,
+        })
+    }
+}
+
+impl ToTokens for DataFile {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        let keyword = &self.keyword;
+        let path = &self.path;
+        let name = self.name_column.as_ref().map(|column| {
+            ::quote::quote! { , name = #column }
+        });
+
+        tokens.extend(::quote::quote! { #keyword(#path #name) });
+    }
+}
+
+/// The directory of the crate which is being compiled, to which the paths of data files are
+/// relative.
+pub fn manifest_dir(span: ::proc_macro2::Span) -> Result<PathBuf> {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| {
+            ::syn::Error::new(
+                span,
+                "Unable to locate data files, since `CARGO_MANIFEST_DIR` is not set",
+            )
+        })
+}
+
+/// A record of a data file, with the line on which it starts.
+pub struct Record {
+    line: usize,
+    fields: Vec<(String, Value)>,
+}
+
+impl Record {
+    fn field(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find_map(|(field, value)| (field == name).then(|| value))
+    }
+}
+
+/// A value of a field in a data file.
+pub enum Value {
+    /// An untyped value, e.g. a field of a CSV file
+    Text(String),
+    String(String),
+    Integer(String),
+    Float(String),
+    Bool(bool),
+    Null,
+    Array(Vec<Value>),
+    Table(Vec<(String, Value)>),
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Text(text) | Value::Integer(text) | Value::Float(text) => f.write_str(text),
+            Value::String(string) => write!(f, "{:?}", string),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Null => f.write_str("null"),
+            Value::Array(values) => {
+                f.write_str("[")?;
+
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    value.fmt(f)?;
+                }
+
+                f.write_str("]")
+            }
+            Value::Table(_) => f.write_str("{ ... }"),
+        }
+    }
+}
+
+/// An error in a data file, on the given line.
+pub struct ParseError {
+    line: usize,
+    message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+/// Converts a name from a data file into a snake case test case id, by replacing each run of
+/// characters which can't be used in an identifier with an underscore.
+pub fn identifier(
+    name: &str,
+    span: ::proc_macro2::Span,
+) -> std::result::Result<::syn::Ident, &'static str> {
+    let mut id = String::new();
+
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            id.extend(c.to_lowercase());
+        } else if !id.is_empty() && !id.ends_with('_') {
+            id.push('_');
+        }
+    }
+
+    let mut id = id.trim_end_matches('_').to_string();
+
+    if id.is_empty() {
+        return Err("expected a test case name, but found");
+    }
+
+    if id.starts_with(|c: char| c.is_numeric()) {
+        id.insert(0, '_');
+    }
+
+    // Keywords, like `fn`, can't be used as a test case id
+    if ::syn::parse_str::<::syn::Ident>(&id).is_err() {
+        id.push('_');
+    }
+
+    ::syn::parse_str::<::syn::Ident>(&id)
+        .map(|mut ident| {
+            ident.set_span(span);
+            ident
+        })
+        .map_err(|_| "unable to create a test case id from")
+}
+
+/// The kind of value a parameter type accepts.
+enum Kind<'a> {
+    Str,
+    String,
+    Bool,
+    Char,
+    Integer(&'static str),
+    Float(&'static str),
+    Option(&'a ::syn::Type),
+    Vec(&'a ::syn::Type),
+    Slice(&'a ::syn::Type),
+    Array(&'a ::syn::Type),
+    Other,
+}
+
+impl<'a> Kind<'a> {
+    fn of(typ: &'a ::syn::Type) -> Self {
+        match typ {
+            ::syn::Type::Group(group) => Kind::of(&group.elem),
+            ::syn::Type::Paren(paren) => Kind::of(&paren.elem),
+            ::syn::Type::Array(array) => Kind::Array(&array.elem),
+            ::syn::Type::Reference(reference) => match reference.elem.as_ref() {
+                ::syn::Type::Path(path) if path.path.is_ident("str") => Kind::Str,
+                ::syn::Type::Slice(slice) => Kind::Slice(&slice.elem),
+                _ => Kind::Other,
+            },
+            ::syn::Type::Path(path) if path.qself.is_none() => {
+                let segment = match path.path.segments.last() {
+                    Some(segment) => segment,
+                    None => return Kind::Other,
+                };
+
+                if let ::syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                    return match (arguments.args.first(), arguments.args.len()) {
+                        (Some(::syn::GenericArgument::Type(inner)), 1)
+                            if segment.ident == "Option" =>
+                        {
+                            Kind::Option(inner)
+                        }
+                        (Some(::syn::GenericArgument::Type(inner)), 1)
+                            if segment.ident == "Vec" =>
+                        {
+                            Kind::Vec(inner)
+                        }
+                        _ => Kind::Other,
+                    };
+                }
+
+                const INTEGERS: [&str; 12] = [
+                    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128",
+                    "usize",
+                ];
+
+                let name = segment.ident.to_string();
+
+                if let Some(integer) = INTEGERS.iter().find(|integer| **integer == name) {
+                    return Kind::Integer(integer);
+                }
+
+                match name.as_str() {
+                    "f32" => Kind::Float("f32"),
+                    "f64" => Kind::Float("f64"),
+                    "bool" => Kind::Bool,
+                    "char" => Kind::Char,
+                    "String" => Kind::String,
+                    _ => Kind::Other,
+                }
+            }
+            _ => Kind::Other,
+        }
+    }
+}
+
+/// Converts a value of a data file into an expression of the given type.
+fn convert(
+    value: &Value,
+    typ: &::syn::Type,
+    span: ::proc_macro2::Span,
+) -> std::result::Result<::proc_macro2::TokenStream, String> {
+    let literal = |mut literal: ::proc_macro2::Literal| {
+        literal.set_span(span);
+        literal.into_token_stream()
+    };
+    let unexpected = || format!("unable to convert `{}`", value);
+
+    match (Kind::of(typ), value) {
+        (Kind::Option(_), Value::Null | Value::Text(_)) if value.is_empty() => {
+            Ok(::quote::quote_spanned! { span=> ::core::option::Option::None })
+        }
+        (Kind::Option(inner), _) => {
+            let inner = convert(value, inner, span)?;
+
+            Ok(::quote::quote_spanned! { span=> ::core::option::Option::Some(#inner) })
+        }
+        (_, Value::Null) => Err(format!(
+            "unable to convert `{}`, which is only supported for `Option` parameters",
+            value
+        )),
+        (Kind::Str, Value::Text(text) | Value::String(text)) => {
+            Ok(literal(::proc_macro2::Literal::string(text)))
+        }
+        (Kind::String, Value::Text(text) | Value::String(text)) => {
+            let text = literal(::proc_macro2::Literal::string(text));
+
+            Ok(::quote::quote_spanned! { span=> ::std::string::String::from(#text) })
+        }
+        (Kind::Bool, Value::Bool(value)) => Ok(::quote::quote_spanned! { span=> #value }),
+        (Kind::Bool, Value::Text(text)) => match text.as_str() {
+            "true" => Ok(::quote::quote_spanned! { span=> true }),
+            "false" => Ok(::quote::quote_spanned! { span=> false }),
+            _ => Err(unexpected()),
+        },
+        (Kind::Char, Value::Text(text) | Value::String(text)) => {
+            let mut chars = text.chars();
+
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(literal(::proc_macro2::Literal::character(c))),
+                _ => Err(unexpected()),
+            }
+        }
+        (Kind::Integer(integer), Value::Text(text) | Value::Integer(text)) => {
+            integer_literal(text, integer)
+                .map(literal)
+                .ok_or_else(unexpected)
+        }
+        (Kind::Float(float), Value::Text(text) | Value::Integer(text) | Value::Float(text)) => {
+            float_expr(text, float, span).ok_or_else(unexpected)
+        }
+        (Kind::Vec(inner), Value::Array(values)) => {
+            let values = convert_all(values, inner, span)?;
+
+            Ok(::quote::quote_spanned! { span=> ::std::vec![#(#values),*] })
+        }
+        (Kind::Slice(inner), Value::Array(values)) => {
+            let values = convert_all(values, inner, span)?;
+
+            Ok(::quote::quote_spanned! { span=> &[#(#values),*] })
+        }
+        (Kind::Array(inner), Value::Array(values)) => {
+            let values = convert_all(values, inner, span)?;
+
+            Ok(::quote::quote_spanned! { span=> [#(#values),*] })
+        }
+        // Other types are parsed from text at runtime, e.g. `std::net::IpAddr`
+        (Kind::Other, Value::Text(text) | Value::String(text)) => {
+            let text = literal(::proc_macro2::Literal::string(text));
+
+            Ok(::quote::quote_spanned! { span=>
+                match <#typ as ::core::str::FromStr>::from_str(#text) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(_) => ::core::panic!(
+                        "unable to parse {:?} as `{}`",
+                        #text,
+                        ::core::stringify!(#typ),
+                    ),
+                }
+            })
+        }
+        (Kind::Other, Value::Integer(text) | Value::Float(text)) => {
+            ::syn::parse_str::<::syn::Expr>(text)
+                .map(|expr| ::quote::quote_spanned! { span=> #expr })
+                .map_err(|_| unexpected())
+        }
+        (Kind::Other, Value::Bool(value)) => Ok(::quote::quote_spanned! { span=> #value }),
+        _ => Err(unexpected()),
+    }
+}
+
+fn convert_all(
+    values: &[Value],
+    typ: &::syn::Type,
+    span: ::proc_macro2::Span,
+) -> std::result::Result<Vec<::proc_macro2::TokenStream>, String> {
+    values
+        .iter()
+        .map(|value| convert(value, typ, span))
+        .collect()
+}
+
+impl Value {
+    fn is_empty(&self) -> bool {
+        match self {
+            Value::Null => true,
+            Value::Text(text) => text.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+/// A suffixed integer literal, if the text is a valid value of the integer type.
+fn integer_literal(text: &str, integer: &str) -> Option<::proc_macro2::Literal> {
+    macro_rules! parse {
+        ($($typ:ident => $constructor:ident),* $(,)?) => {
+            match integer {
+                $(stringify!($typ) => text.parse::<$typ>().ok().map(::proc_macro2::Literal::$constructor),)*
+                _ => None,
+            }
+        };
+    }
+
+    parse! {
+        i8 => i8_suffixed,
+        i16 => i16_suffixed,
+        i32 => i32_suffixed,
+        i64 => i64_suffixed,
+        i128 => i128_suffixed,
+        isize => isize_suffixed,
+        u8 => u8_suffixed,
+        u16 => u16_suffixed,
+        u32 => u32_suffixed,
+        u64 => u64_suffixed,
+        u128 => u128_suffixed,
+        usize => usize_suffixed,
+    }
+}
+
+/// A float expression, if the text is a valid float. Besides numbers, `nan`, `inf` and `-inf` are
+/// accepted, and numbers which are out of range for the float type become an infinity.
+fn float_expr(
+    text: &str,
+    float: &str,
+    span: ::proc_macro2::Span,
+) -> Option<::proc_macro2::TokenStream> {
+    let typ = ::syn::Ident::new(float, span);
+
+    let constant = match text.trim_start_matches('+').to_ascii_lowercase().as_str() {
+        "nan" | "-nan" => Some("NAN"),
+        "inf" | "infinity" => Some("INFINITY"),
+        "-inf" | "-infinity" => Some("NEG_INFINITY"),
+        _ => None,
+    };
+
+    if let Some(constant) = constant {
+        return Some(float_constant(&typ, constant, span));
+    }
+
+    // A float literal must be finite, while e.g. `1e999` parses as an infinity
+    let value = match float {
+        "f32" => f64::from(text.parse::<f32>().ok()?),
+        _ => text.parse::<f64>().ok()?,
+    };
+
+    if value.is_nan() {
+        return Some(float_constant(&typ, "NAN", span));
+    } else if value.is_infinite() {
+        let constant = if value > 0.0 {
+            "INFINITY"
+        } else {
+            "NEG_INFINITY"
+        };
+
+        return Some(float_constant(&typ, constant, span));
+    }
+
+    let mut literal = match float {
+        "f32" => ::proc_macro2::Literal::f32_suffixed(value as f32),
+        _ => ::proc_macro2::Literal::f64_suffixed(value),
+    };
+    literal.set_span(span);
+
+    Some(literal.into_token_stream())
+}
+
+/// An associated constant of a float type, e.g. `<f64>::INFINITY`.
+fn float_constant(
+    typ: &::syn::Ident,
+    constant: &str,
+    span: ::proc_macro2::Span,
+) -> ::proc_macro2::TokenStream {
+    let constant = ::syn::Ident::new(constant, span);
+
+    ::quote::quote_spanned! { span=> <#typ>::#constant }
+}
@@ -0,0 +1,121 @@
+use super::{ParseError, Record, Value};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Parses a CSV file, of which the first row holds the names of the columns. Fields may be quoted
+/// with double quotes, in which case they may contain commas, newlines and escaped (`""`) quotes.
+pub fn parse(source: &str) -> Result<Vec<Record>, ParseError> {
+    let mut rows = Rows::new(source);
+
+    let header = match rows.next_row()? {
+        Some((_, header)) => header,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut records = Vec::new();
+
+    while let Some((line, fields)) = rows.next_row()? {
+        if fields.len() != header.len() {
+            return Err(ParseError::new(
+                line,
+                format!(
+                    "expected {} fields, like the header row, but found {}",
+                    header.len(),
+                    fields.len()
+                ),
+            ));
+        }
+
+        records.push(Record {
+            line,
+            fields: header
+                .iter()
+                .cloned()
+                .zip(fields.into_iter().map(Value::Text))
+                .collect(),
+        });
+    }
+
+    Ok(records)
+}
+
+struct Rows<'source> {
+    chars: Peekable<Chars<'source>>,
+    line: usize,
+}
+
+impl<'source> Rows<'source> {
+    fn new(source: &'source str) -> Self {
+        Rows {
+            chars: source.chars().peekable(),
+            line: 1,
+        }
+    }
+
+    /// The next non-empty row, with the line on which it starts.
+    fn next_row(&mut self) -> Result<Option<(usize, Vec<String>)>, ParseError> {
+        while self.chars.peek().is_some() {
+            let line = self.line;
+            let fields = self.row()?;
+
+            if fields.len() > 1 || !fields[0].is_empty() {
+                return Ok(Some((line, fields)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn row(&mut self) -> Result<Vec<String>, ParseError> {
+        let mut fields = Vec::new();
+
+        loop {
+            fields.push(self.field()?);
+
+            match self.chars.next() {
+                Some(',') => {}
+                Some('\r') => {
+                    self.chars.next_if_eq(&'\n');
+                    self.line += 1;
+
+                    return Ok(fields);
+                }
+                Some('\n') => {
+                    self.line += 1;
+
+                    return Ok(fields);
+                }
+                _ => return Ok(fields),
+            }
+        }
+    }
+
+    fn field(&mut self) -> Result<String, ParseError> {
+        let mut field = String::new();
+
+        if self.chars.next_if_eq(&'"').is_some() {
+            let line = self.line;
+
+            loop {
+                match self.chars.next() {
+                    Some('"') if self.chars.next_if_eq(&'"').is_some() => field.push('"'),
+                    Some('"') => break,
+                    Some(c) => {
+                        if c == '\n' {
+                            self.line += 1;
+                        }
+
+                        field.push(c);
+                    }
+                    None => return Err(ParseError::new(line, "unterminated quoted field")),
+                }
+            }
+        }
+
+        while let Some(c) = self.chars.next_if(|c| !matches!(c, ',' | '\r' | '\n')) {
+            field.push(c);
+        }
+
+        Ok(field)
+    }
+}
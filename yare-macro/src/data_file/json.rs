@@ -0,0 +1,227 @@
+use super::{ParseError, Record, Value};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Parses a JSON file, which holds an array of objects.
+pub fn parse(source: &str) -> Result<Vec<Record>, ParseError> {
+    let mut parser = Parser {
+        chars: source.chars().peekable(),
+        line: 1,
+    };
+
+    parser.whitespace();
+    parser.expect('[', "expected an array of objects")?;
+
+    let mut records = Vec::new();
+
+    parser.whitespace();
+
+    if parser.chars.next_if_eq(&']').is_none() {
+        loop {
+            parser.whitespace();
+
+            let line = parser.line;
+
+            match parser.value()? {
+                Value::Table(fields) => records.push(Record { line, fields }),
+                _ => return Err(ParseError::new(line, "expected an object")),
+            }
+
+            parser.whitespace();
+
+            if parser.chars.next_if_eq(&',').is_none() {
+                parser.expect(']', "expected `,` or `]`")?;
+                break;
+            }
+        }
+    }
+
+    parser.whitespace();
+
+    match parser.chars.peek() {
+        Some(_) => Err(ParseError::new(
+            parser.line,
+            "unexpected content after the array",
+        )),
+        None => Ok(records),
+    }
+}
+
+struct Parser<'source> {
+    chars: Peekable<Chars<'source>>,
+    line: usize,
+}
+
+impl Parser<'_> {
+    fn value(&mut self) -> Result<Value, ParseError> {
+        self.whitespace();
+
+        match self.chars.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => self.string().map(Value::String),
+            Some('-' | '0'..='9') => Ok(self.number()),
+            Some('t' | 'f' | 'n') => self.keyword(),
+            _ => Err(ParseError::new(self.line, "expected a value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, ParseError> {
+        self.expect('{', "expected an object")?;
+
+        let mut fields = Vec::new();
+
+        self.whitespace();
+
+        if self.chars.next_if_eq(&'}').is_some() {
+            return Ok(Value::Table(fields));
+        }
+
+        loop {
+            self.whitespace();
+
+            let key = self.string()?;
+
+            self.whitespace();
+            self.expect(':', "expected `:` after the key of a field")?;
+
+            fields.push((key, self.value()?));
+
+            self.whitespace();
+
+            if self.chars.next_if_eq(&',').is_none() {
+                self.expect('}', "expected `,` or `}`")?;
+
+                return Ok(Value::Table(fields));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.expect('[', "expected an array")?;
+
+        let mut values = Vec::new();
+
+        self.whitespace();
+
+        if self.chars.next_if_eq(&']').is_some() {
+            return Ok(Value::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+
+            self.whitespace();
+
+            if self.chars.next_if_eq(&',').is_none() {
+                self.expect(']', "expected `,` or `]`")?;
+
+                return Ok(Value::Array(values));
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"', "expected a string")?;
+
+        let mut string = String::new();
+
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(string),
+                Some('\\') => string.push(self.escape()?),
+                Some('\n') | None => return Err(ParseError::new(self.line, "unterminated string")),
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, ParseError> {
+        match self.chars.next() {
+            Some('"') => Ok('"'),
+            Some('\\') => Ok('\\'),
+            Some('/') => Ok('/'),
+            Some('b') => Ok('\u{8}'),
+            Some('f') => Ok('\u{c}'),
+            Some('n') => Ok('\n'),
+            Some('r') => Ok('\r'),
+            Some('t') => Ok('\t'),
+            Some('u') => {
+                let high = self.code_unit()?;
+
+                // Characters outside of the basic multilingual plane are encoded as a surrogate pair
+                let code_point = if (0xd800..0xdc00).contains(&high) {
+                    self.expect('\\', "expected a low surrogate")?;
+                    self.expect('u', "expected a low surrogate")?;
+
+                    let low = self.code_unit()?;
+
+                    0x10000 + ((high - 0xd800) << 10) + (low.wrapping_sub(0xdc00) & 0x3ff)
+                } else {
+                    high
+                };
+
+                char::from_u32(code_point)
+                    .ok_or_else(|| ParseError::new(self.line, "invalid unicode escape"))
+            }
+            _ => Err(ParseError::new(self.line, "invalid escape sequence")),
+        }
+    }
+
+    fn code_unit(&mut self) -> Result<u32, ParseError> {
+        let digits = (0..4).filter_map(|_| self.chars.next()).collect::<String>();
+
+        u32::from_str_radix(&digits, 16)
+            .map_err(|_| ParseError::new(self.line, "invalid unicode escape"))
+    }
+
+    fn number(&mut self) -> Value {
+        let mut number = String::new();
+
+        while let Some(c) = self
+            .chars
+            .next_if(|c| matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9'))
+        {
+            number.push(c);
+        }
+
+        if number.contains(|c| matches!(c, '.' | 'e' | 'E')) {
+            Value::Float(number)
+        } else {
+            Value::Integer(number)
+        }
+    }
+
+    fn keyword(&mut self) -> Result<Value, ParseError> {
+        let mut keyword = String::new();
+
+        while let Some(c) = self.chars.next_if(char::is_ascii_alphabetic) {
+            keyword.push(c);
+        }
+
+        match keyword.as_str() {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            "null" => Ok(Value::Null),
+            _ => Err(ParseError::new(
+                self.line,
+                format!("unexpected `{}`, expected a value", keyword),
+            )),
+        }
+    }
+
+    fn whitespace(&mut self) {
+        while let Some(c) = self.chars.next_if(|c| c.is_ascii_whitespace()) {
+            if c == '\n' {
+                self.line += 1;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char, message: &str) -> Result<(), ParseError> {
+        match self.chars.next_if_eq(&expected) {
+            Some(_) => Ok(()),
+            None => Err(ParseError::new(self.line, message)),
+        }
+    }
+}
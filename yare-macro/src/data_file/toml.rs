@@ -0,0 +1,257 @@
+use super::{ParseError, Record, Value};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Parses a TOML file, of which the records are the tables of a single array of tables, e.g.
+/// `[[vectors]]`. Keys outside of the array of tables are ignored.
+///
+/// Only the subset of TOML which is useful for test vectors is supported: key/value pairs with
+/// strings, integers, floats, booleans and single-line arrays of those.
+pub fn parse(source: &str) -> Result<Vec<Record>, ParseError> {
+    let mut array_name = None;
+    let mut records = Vec::<Record>::new();
+
+    for (index, text) in source.lines().enumerate() {
+        let line = index + 1;
+        let text = text.trim();
+
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = text
+            .strip_prefix("[[")
+            .and_then(|text| text.split("]]").next())
+        {
+            let name = name.trim();
+
+            match &array_name {
+                Some(array_name) if array_name != name => {
+                    return Err(ParseError::new(
+                        line,
+                        format!(
+                            "expected a single array of tables, but found `[[{}]]` after `[[{}]]`",
+                            name, array_name
+                        ),
+                    ))
+                }
+                _ => array_name = Some(name.to_string()),
+            }
+
+            records.push(Record {
+                line,
+                fields: Vec::new(),
+            });
+        } else if text.starts_with('[') {
+            return Err(ParseError::new(
+                line,
+                "expected an array of tables, e.g. `[[cases]]`; other tables are not supported",
+            ));
+        } else {
+            let field = Parser {
+                chars: text.chars().peekable(),
+                line,
+            }
+            .key_value()?;
+
+            if let Some(record) = records.last_mut() {
+                record.fields.push(field);
+            }
+        }
+    }
+
+    Ok(records)
+}
+
+struct Parser<'source> {
+    chars: Peekable<Chars<'source>>,
+    line: usize,
+}
+
+impl Parser<'_> {
+    fn key_value(&mut self) -> Result<(String, Value), ParseError> {
+        let key = match self.chars.peek() {
+            Some('"') => self.basic_string()?,
+            Some('\'') => self.literal_string()?,
+            _ => self.bare(),
+        };
+
+        if key.is_empty() {
+            return Err(self.error("expected a key"));
+        }
+
+        self.whitespace();
+        self.expect('=', "expected `=` after the key")?;
+
+        let value = self.value()?;
+
+        self.whitespace();
+
+        match self.chars.peek() {
+            None | Some('#') => Ok((key, value)),
+            Some(_) => Err(self.error("unexpected content after the value")),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        self.whitespace();
+
+        match self.chars.peek() {
+            Some('"') => self.basic_string().map(Value::String),
+            Some('\'') => self.literal_string().map(Value::String),
+            Some('[') => self.array(),
+            Some('{') => Err(self.error("inline tables are not supported")),
+            Some(_) => self.scalar(),
+            None => Err(self.error("expected a value")),
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.expect('[', "expected an array")?;
+
+        let mut values = Vec::new();
+
+        loop {
+            self.whitespace();
+
+            if self.chars.next_if_eq(&']').is_some() {
+                return Ok(Value::Array(values));
+            }
+
+            values.push(self.value()?);
+
+            self.whitespace();
+
+            if self.chars.next_if_eq(&',').is_none() {
+                self.expect(']', "expected `,` or `]`; arrays must be on a single line")?;
+
+                return Ok(Value::Array(values));
+            }
+        }
+    }
+
+    fn basic_string(&mut self) -> Result<String, ParseError> {
+        self.expect('"', "expected a string")?;
+
+        let mut string = String::new();
+
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(string),
+                Some('\\') => string.push(self.escape()?),
+                Some(c) => string.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn literal_string(&mut self) -> Result<String, ParseError> {
+        self.expect('\'', "expected a string")?;
+
+        let mut string = String::new();
+
+        loop {
+            match self.chars.next() {
+                Some('\'') => return Ok(string),
+                Some(c) => string.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, ParseError> {
+        let digits = match self.chars.next() {
+            Some('"') => return Ok('"'),
+            Some('\\') => return Ok('\\'),
+            Some('b') => return Ok('\u{8}'),
+            Some('f') => return Ok('\u{c}'),
+            Some('n') => return Ok('\n'),
+            Some('r') => return Ok('\r'),
+            Some('t') => return Ok('\t'),
+            Some('u') => 4,
+            Some('U') => 8,
+            _ => return Err(self.error("invalid escape sequence")),
+        };
+
+        let code_point = (0..digits)
+            .filter_map(|_| self.chars.next())
+            .collect::<String>();
+
+        u32::from_str_radix(&code_point, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    /// An integer, float or boolean.
+    fn scalar(&mut self) -> Result<Value, ParseError> {
+        let text = self.bare_with(|c| matches!(c, '+' | '-' | '.' | '_'));
+
+        match text.as_str() {
+            "true" => return Ok(Value::Bool(true)),
+            "false" => return Ok(Value::Bool(false)),
+            "inf" | "+inf" | "-inf" | "nan" | "+nan" | "-nan" => return Ok(Value::Float(text)),
+            _ => {}
+        }
+
+        let number = text.replace('_', "");
+        let (sign, digits) = match number.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", number.trim_start_matches('+')),
+        };
+
+        let radix = match digits.get(..2) {
+            Some("0x") => Some(16),
+            Some("0o") => Some(8),
+            Some("0b") => Some(2),
+            _ => None,
+        };
+
+        if let Some(radix) = radix {
+            return u128::from_str_radix(&digits[2..], radix)
+                .map(|value| Value::Integer(value.to_string()))
+                .map_err(|_| self.error(format!("invalid integer `{}`", text)));
+        }
+
+        if digits.parse::<u128>().is_ok() {
+            Ok(Value::Integer(format!("{}{}", sign, digits)))
+        } else if digits.parse::<f64>().is_ok() && digits.starts_with(|c: char| c.is_ascii_digit())
+        {
+            Ok(Value::Float(format!("{}{}", sign, digits)))
+        } else {
+            Err(self.error(format!("unsupported value `{}`", text)))
+        }
+    }
+
+    fn bare(&mut self) -> String {
+        self.bare_with(|c| c == '-')
+    }
+
+    fn bare_with(&mut self, allowed: impl Fn(char) -> bool) -> String {
+        let mut text = String::new();
+
+        while let Some(c) = self
+            .chars
+            .next_if(|c| c.is_ascii_alphanumeric() || *c == '_' || allowed(*c))
+        {
+            text.push(c);
+        }
+
+        text
+    }
+
+    fn whitespace(&mut self) {
+        while self.chars.next_if(|c| *c == ' ' || *c == '\t').is_some() {}
+    }
+
+    fn expect(&mut self, expected: char, message: &str) -> Result<(), ParseError> {
+        match self.chars.next_if_eq(&expected) {
+            Some(_) => Ok(()),
+            None => Err(self.error(message)),
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, message)
+    }
+}
@@ -0,0 +1,27 @@
+/// Collects errors, so all of them can be reported at once, instead of just the first one.
+#[derive(Default)]
+pub struct Diagnostics {
+    error: Option<::syn::Error>,
+}
+
+impl Diagnostics {
+    pub fn push(&mut self, error: ::syn::Error) {
+        match &mut self.error {
+            Some(existing) => existing.combine(error),
+            None => self.error = Some(error),
+        }
+    }
+
+    /// Takes the value of a result, or collects its error.
+    pub fn take<T>(&mut self, result: ::syn::Result<T>) -> Option<T> {
+        result.map_err(|error| self.push(error)).ok()
+    }
+
+    /// Returns the given value if no errors were collected, or all collected errors otherwise.
+    pub fn finish<T>(self, value: T) -> ::syn::Result<T> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(value),
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use crate::data_file::identifier;
+use crate::diagnostics::Diagnostics;
+use crate::test_cases::TestCase;
+use crate::test_fn::TestFn;
+use quote::ToTokens;
+use syn::parse::Result;
+use syn::punctuated::Punctuated;
+
+/// Test cases from the first Markdown table in the doc comment of the test function, as enabled
+/// with the `doc_table` option.
+///
+/// The first column holds the name of each test case, and the other columns are mapped to the
+/// parameters of the test function by their header. Each cell holds a Rust expression, which may be
+/// wrapped in backticks, e.g. `` `"yare"` ``. A `|` within a cell must be escaped as `\|`.
+pub fn test_cases(
+    test_fn: &TestFn,
+    parameters: &[(&::syn::Ident, &::syn::Type)],
+    option: &::syn::Ident,
+) -> Result<Vec<TestCase>> {
+    let rows = table(&test_fn.attributes());
+
+    let (header, separator, rows) = match rows.as_slice() {
+        [header, separator, rows @ ..] if is_separator(&separator.cells) => {
+            (header, separator, rows)
+        }
+        _ => {
+            return Err(::syn::Error::new(
+                option.span(),
+                "Expected a Markdown table in the doc comment of the test function, with a header row, e.g. `| case | a | b |`, followed by a separator row, e.g. `|---|---|---|`",
+            ))
+        }
+    };
+
+    let mut diagnostics = Diagnostics::default();
+
+    // The column of each parameter, after the column with the names of the test cases
+    let columns = parameters
+        .iter()
+        .filter_map(|(ident, _)| {
+            let column = header
+                .cells
+                .iter()
+                .skip(1)
+                .position(|cell| *cell == ident.to_string())
+                .map(|position| position + 1);
+
+            if column.is_none() {
+                diagnostics.push(::syn::Error::new(
+                    header.span,
+                    format_args!("Missing a column for parameter `{}`", ident),
+                ));
+            }
+
+            column
+        })
+        .collect::<Vec<_>>();
+
+    for cell in header.cells.iter().skip(1) {
+        if !parameters.iter().any(|(ident, _)| *ident == cell) {
+            diagnostics.push(::syn::Error::new(
+                header.span,
+                format_args!("Column `{}` doesn't match a parameter", cell),
+            ));
+        }
+    }
+
+    if separator.cells.len() != header.cells.len() {
+        diagnostics.push(::syn::Error::new(
+            separator.span,
+            "The separator row must have a cell for each column of the header",
+        ));
+    }
+
+    diagnostics.finish(())?;
+
+    let mut diagnostics = Diagnostics::default();
+
+    let cases = rows
+        .iter()
+        .filter_map(|row| diagnostics.take(test_case(row, header, &columns)))
+        .collect();
+
+    diagnostics.finish(cases)
+}
+
+/// A test case for a row of the table.
+fn test_case(row: &Row, header: &Row, columns: &[usize]) -> Result<TestCase> {
+    if row.cells.len() != header.cells.len() {
+        return Err(::syn::Error::new(
+            row.span,
+            format_args!(
+                "Expected {} cells, like the header, but the row has {}",
+                header.cells.len(),
+                row.cells.len()
+            ),
+        ));
+    }
+
+    let name = &row.cells[0];
+    let id = identifier(name, row.span)
+        .map_err(|message| ::syn::Error::new(row.span, format_args!("{} `{}`", message, name)))?;
+
+    let arguments = columns
+        .iter()
+        .map(|column| {
+            let cell = &row.cells[*column];
+
+            let expr = ::syn::parse_str::<::syn::Expr>(cell).map_err(|error| {
+                ::syn::Error::new(
+                    row.span,
+                    format_args!(
+                        "Unable to parse `{}` in column `{}` as an expression: {}",
+                        cell, header.cells[*column], error
+                    ),
+                )
+            })?;
+
+            ::syn::parse2::<::syn::Expr>(respan(expr.into_token_stream(), row.span))
+        })
+        .collect::<Result<Punctuated<::syn::Expr, Token![,]>>>()?;
+
+    Ok(TestCase::generated(id, arguments))
+}
+
+/// A row of a Markdown table, spanned at the doc comment it was written in.
+struct Row {
+    cells: Vec<String>,
+    span: ::proc_macro2::Span,
+}
+
+/// The rows of the first Markdown table in the doc comments.
+fn table(attributes: &[::syn::Attribute]) -> Vec<Row> {
+    let lines = attributes
+        .iter()
+        .filter_map(|attribute| match &attribute.meta {
+            ::syn::Meta::NameValue(::syn::MetaNameValue {
+                path,
+                value:
+                    ::syn::Expr::Lit(::syn::ExprLit {
+                        lit: ::syn::Lit::Str(doc),
+                        ..
+                    }),
+                ..
+            }) if path.is_ident("doc") => Some(doc),
+            _ => None,
+        });
+
+    let mut rows = Vec::new();
+
+    for doc in lines {
+        // A block doc comment holds many lines
+        for line in doc.value().lines() {
+            match line.trim().strip_prefix('|') {
+                Some(row) => rows.push(Row {
+                    cells: cells(row),
+                    span: doc.span(),
+                }),
+                None if !rows.is_empty() => return rows,
+                None => {}
+            }
+        }
+    }
+
+    rows
+}
+
+/// The cells of a row, without its leading `|`.
+fn cells(row: &str) -> Vec<String> {
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = row.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => cell.extend(chars.next()),
+            '|' => cells.push(std::mem::take(&mut cell)),
+            c => cell.push(c),
+        }
+    }
+
+    // The trailing `|` is optional
+    if !cell.trim().is_empty() {
+        cells.push(cell);
+    }
+
+    cells
+        .iter()
+        .map(|cell| {
+            let cell = cell.trim();
+
+            // Code spans are rendered as code by rustdoc, but only their content is the expression
+            match cell
+                .strip_prefix('`')
+                .and_then(|cell| cell.strip_suffix('`'))
+            {
+                Some(code) => code.trim().to_string(),
+                None => cell.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Whether the cells are those of the separator row, e.g. `|---|:---:|`.
+fn is_separator(cells: &[String]) -> bool {
+    !cells.is_empty()
+        && cells.iter().all(|cell| {
+            let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+
+            !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+        })
+}
+
+/// Sets the span of each token, so errors in an expression point at the row of the table.
+fn respan(
+    tokens: ::proc_macro2::TokenStream,
+    span: ::proc_macro2::Span,
+) -> ::proc_macro2::TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let ::proc_macro2::TokenTree::Group(group) = &token {
+                let mut respanned =
+                    ::proc_macro2::Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                token = ::proc_macro2::TokenTree::Group(respanned);
+            } else {
+                token.set_span(span);
+            }
+
+            token
+        })
+        .collect()
+}
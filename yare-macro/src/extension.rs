@@ -0,0 +1,299 @@
+use crate::diagnostics::Diagnostics;
+use crate::runner::Harness;
+use crate::test_cases::TestCase;
+use quote::ToTokens;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+
+/// The arguments of a test case which extends another test case, by overriding the arguments of
+/// some of its parameters.
+/// The syntax looks like this `base { port = 0 }`, or like this `extends(base) { port = 0 }`, where
+/// `base` is the id of another test case of the test function.
+#[derive(Clone)]
+pub struct Extension {
+    keyword: Option<(::syn::Ident, ::syn::token::Paren)>,
+    base: ::syn::Ident,
+    braces: ::syn::token::Brace,
+    overrides: Punctuated<Override, Token![,]>,
+}
+
+/// The argument for a single parameter, e.g. `port = 0`.
+#[derive(Clone)]
+struct Override {
+    parameter: ::syn::Ident,
+    assignment: Token![=],
+    value: ::syn::Expr,
+}
+
+impl Extension {
+    /// Whether the input starts with `base {` or `extends(base) {`.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        match fork.parse::<::syn::Ident>() {
+            Ok(keyword) if keyword == "extends" && fork.peek(::syn::token::Paren) => true,
+            Ok(_) => fork.peek(::syn::token::Brace),
+            Err(_) => false,
+        }
+    }
+
+    pub fn braces(&self) -> ::syn::token::Brace {
+        self.braces
+    }
+
+    /// The test case with the arguments of its base, where the arguments of the overridden
+    /// parameters have been replaced. Bases may themselves extend another test case.
+    ///
+    /// Returns `None` when an error further down the chain of bases is reported for the test case
+    /// which is in error itself.
+    pub fn resolve(
+        &self,
+        case: &TestCase,
+        cases: &HashMap<String, &TestCase>,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+    ) -> Result<Option<TestCase>> {
+        self.check_overrides(case.id(), parameters)?;
+
+        let id = case.id().to_string();
+        let mut chain = vec![id.clone()];
+        let mut extensions = vec![self];
+
+        let base = loop {
+            let extension = extensions[extensions.len() - 1];
+            let name = extension.base.to_string();
+            let direct = extensions.len() == 1;
+
+            if name == id {
+                chain.push(name);
+
+                return Err(::syn::Error::new(
+                    self.base.span(),
+                    format_args!(
+                        "Test case `{}` extends itself: {}",
+                        id,
+                        chain
+                            .iter()
+                            .map(|id| format!("`{}`", id))
+                            .collect::<Vec<_>>()
+                            .join(" -> "),
+                    ),
+                ));
+            }
+
+            // A cycle which doesn't include this test case is reported by the test cases in it
+            if chain.contains(&name) {
+                return Ok(None);
+            }
+
+            let base = match cases.get(&name) {
+                Some(base) => *base,
+                None if direct => {
+                    return Err(::syn::Error::new(
+                        extension.base.span(),
+                        format_args!(
+                            "Unknown base test case `{}`; expected the id of another test case of this test function",
+                            name,
+                        ),
+                    ))
+                }
+                None => return Ok(None),
+            };
+
+            chain.push(name);
+
+            match base.extension() {
+                Some(extension) => extensions.push(extension),
+                None => break base,
+            }
+        };
+
+        let base_ident = &extensions[extensions.len() - 1].base;
+        let direct = extensions.len() == 1;
+
+        let error = if base.is_property() {
+            Some("is a property-based test case")
+        } else if base.arguments().len() != parameters.len() {
+            Some("doesn't have an argument for each parameter")
+        } else {
+            None
+        };
+
+        match error {
+            Some(reason) if direct => {
+                return Err(::syn::Error::new(
+                    base_ident.span(),
+                    format_args!(
+                        "Test case `{}` can't be extended, since it {}",
+                        base_ident, reason
+                    ),
+                ))
+            }
+            Some(_) => return Ok(None),
+            None => {}
+        }
+
+        let mut arguments = base.arguments().clone();
+
+        // The overrides closest to this test case are applied last
+        for extension in extensions.iter().rev() {
+            for value in extension.overrides.iter() {
+                if let Some(position) = parameters
+                    .iter()
+                    .position(|(ident, _)| **ident == value.parameter)
+                {
+                    arguments[position] = value.value.clone();
+                }
+            }
+        }
+
+        Ok(Some(case.with_arguments(arguments)))
+    }
+
+    /// Checks that each overridden parameter exists, and is overridden at most once.
+    fn check_overrides(
+        &self,
+        id: &::syn::Ident,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+    ) -> Result<()> {
+        let mut diagnostics = Diagnostics::default();
+
+        for (index, value) in self.overrides.iter().enumerate() {
+            if !parameters
+                .iter()
+                .any(|(ident, _)| **ident == value.parameter)
+            {
+                diagnostics.push(::syn::Error::new(
+                    value.parameter.span(),
+                    format_args!(
+                        "{}: The test function has no parameter named `{}`, expected one of: {}",
+                        id,
+                        value.parameter,
+                        parameters
+                            .iter()
+                            .map(|(ident, _)| format!("`{}`", ident))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                ));
+            } else if self
+                .overrides
+                .iter()
+                .take(index)
+                .any(|previous| previous.parameter == value.parameter)
+            {
+                diagnostics.push(::syn::Error::new(
+                    value.parameter.span(),
+                    format_args!(
+                        "{}: Parameter `{}` is overridden more than once",
+                        id, value.parameter,
+                    ),
+                ));
+            }
+        }
+
+        diagnostics.finish(())
+    }
+}
+
+/// Replaces the test cases which extend another test case by their resolved test case. The base
+/// may be a test case of any #[parameterized(...)] attribute of the test function. Test cases which
+/// can't be resolved are left out, and their errors are collected.
+pub fn resolve_all<'a>(
+    cases: Vec<(Harness, Cow<'a, TestCase>)>,
+    parameters: &[(&::syn::Ident, &::syn::Type)],
+    diagnostics: &mut Diagnostics,
+) -> Vec<(Harness, Cow<'a, TestCase>)> {
+    let mut by_id = HashMap::<String, &TestCase>::new();
+
+    for (_, case) in cases.iter() {
+        by_id.entry(case.id().to_string()).or_insert(case);
+    }
+
+    let resolved = cases
+        .iter()
+        .map(|(_, case)| {
+            let extension = case.extension()?;
+
+            Some(
+                diagnostics
+                    .take(extension.resolve(case, &by_id, parameters))
+                    .flatten(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    cases
+        .into_iter()
+        .zip(resolved)
+        .filter_map(|((harness, case), resolved)| match resolved {
+            None => Some((harness, case)),
+            Some(extended) => extended.map(|extended| (harness, Cow::Owned(extended))),
+        })
+        .collect()
+}
+
+impl Parse for Extension {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident = input.parse::<::syn::Ident>()?;
+
+        let (keyword, base) = if ident == "extends" && input.peek(::syn::token::Paren) {
+            let content;
+            let parens = parenthesized!(content in input);
+            let base = content.parse()?;
+
+            if !content.is_empty() {
+                return Err(content.error(
+                    "Expected the id of a single test case, e.g. `extends(base) { port = 0 }`",
+                ));
+            }
+
+            (Some((ident, parens)), base)
+        } else {
+            (None, ident)
+        };
+
+        let content;
+
+        Ok(Extension {
+            keyword,
+            base,
+            braces: braced!(content in input),
+            overrides: Punctuated::parse_terminated(&content)?,
+        })
+    }
+}
+
+impl ToTokens for Extension {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        match &self.keyword {
+            Some((keyword, parens)) => {
+                keyword.to_tokens(tokens);
+                parens.surround(tokens, |tokens| self.base.to_tokens(tokens));
+            }
+            None => self.base.to_tokens(tokens),
+        }
+
+        self.braces
+            .surround(tokens, |tokens| self.overrides.to_tokens(tokens));
+    }
+}
+
+impl Parse for Override {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Override {
+            parameter: input.parse()?,
+            assignment: input.parse()?,
+            value: crate::cfg_select::parse_argument(input)?,
+        })
+    }
+}
+
+impl ToTokens for Override {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.parameter.to_tokens(tokens);
+        self.assignment.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,354 @@
+use crate::data_file::{identifier, manifest_dir};
+use crate::test_cases::{Origin, TestCase};
+use quote::ToTokens;
+use std::path::{Path, PathBuf};
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+
+/// Test cases for each file which matches a glob pattern.
+/// The syntax looks like this `files = "tests/fixtures/**/*.input"`.
+///
+/// The first parameter of the test function receives the matching file, and the optional second
+/// parameter receives the sibling file with the `.expected` extension. The id of a test case is
+/// derived from the path of the file, relative to the directory in which the search starts.
+#[derive(Clone)]
+pub struct FileGlob {
+    keyword: ::syn::Ident,
+    assignment: Token![=],
+    pattern: ::syn::LitStr,
+}
+
+impl FileGlob {
+    /// Whether the input starts with a file glob entry, and not with a test case named `files`.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        match fork.parse::<::syn::Ident>() {
+            Ok(keyword) if keyword == "files" => fork.peek(Token![=]) && fork.peek2(::syn::LitStr),
+            _ => false,
+        }
+    }
+
+    /// A test case for each matching file.
+    pub fn test_cases(
+        &self,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+    ) -> Result<Vec<TestCase>> {
+        let span = self.pattern.span();
+
+        let (input, expected) = match parameters {
+            [input] => (input, None),
+            [input, expected] => (input, Some(expected)),
+            _ => {
+                return Err(::syn::Error::new(
+                    span,
+                    format_args!(
+                        "Expected a parameter for the matching file, and optionally a second parameter for its `.expected` file, but the test function has {} parameters",
+                        parameters.len()
+                    ),
+                ))
+            }
+        };
+
+        let (manifest_dir, base, files) = self.matching_files()?;
+
+        files
+            .iter()
+            .map(|relative| {
+                let file = Path::new(&base).join(relative);
+                let name = relative.with_extension("");
+                let id = identifier(&name.to_string_lossy(), span).map_err(|message| {
+                    ::syn::Error::new(span, format_args!("{} `{}`", message, relative.display()))
+                })?;
+
+                let mut arguments = Punctuated::new();
+                let mut sources = vec![file.display().to_string()];
+
+                arguments.push(self.argument(&manifest_dir, &file, input, false)?);
+
+                if let Some(expected) = expected {
+                    let file = file.with_extension("expected");
+
+                    sources.push(file.display().to_string());
+                    arguments.push(self.argument(&manifest_dir, &file, expected, true)?);
+                }
+
+                Ok(TestCase::new(
+                    id,
+                    arguments,
+                    Origin {
+                        file: file.display().to_string(),
+                        line: 1,
+                        sources,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Includes each matching file, and its `.expected` file if it exists, in the generated code,
+    /// so the test cases are regenerated when one of them changes, also when it's passed as a
+    /// path. Errors are reported by `test_cases`.
+    pub fn tracking(&self) -> ::proc_macro2::TokenStream {
+        let (manifest_dir, base, files) = match self.matching_files() {
+            Ok(matching) => matching,
+            Err(_) => return ::proc_macro2::TokenStream::new(),
+        };
+
+        let tracked = files
+            .iter()
+            .map(|relative| manifest_dir.join(&base).join(relative))
+            .flat_map(|file| vec![file.with_extension("expected"), file])
+            .filter(|file| file.is_file())
+            .map(|file| file.to_string_lossy().into_owned());
+
+        ::quote::quote_spanned! { self.pattern.span()=>
+            #(const _: &[u8] = ::core::include_bytes!(#tracked);)*
+        }
+    }
+
+    /// The manifest directory, the directory in which the search starts, and the files which
+    /// match the pattern, relative to that directory, in order.
+    fn matching_files(&self) -> Result<(PathBuf, String, Vec<PathBuf>)> {
+        let span = self.pattern.span();
+        let pattern = self.pattern.value();
+        let manifest_dir = manifest_dir(span)?;
+
+        // The search starts in the longest leading directory without wildcards
+        let components = pattern.split('/').collect::<Vec<_>>();
+        let fixed = components
+            .iter()
+            .take(components.len() - 1)
+            .take_while(|component| !component.contains(['*', '?']))
+            .count();
+
+        let base = components[..fixed].join("/");
+        let mut files = Vec::new();
+
+        walk(
+            &manifest_dir.join(&base),
+            PathBuf::new(),
+            &components[fixed..],
+            &mut files,
+        );
+
+        if files.is_empty() {
+            return Err(::syn::Error::new(
+                span,
+                format_args!("No files match `{}`", pattern),
+            ));
+        }
+
+        files.sort();
+
+        Ok((manifest_dir, base, files))
+    }
+
+    /// The argument for a parameter which receives a file, depending on the type of the
+    /// parameter. The `.expected` file may be missing if its parameter is an `Option`.
+    fn argument(
+        &self,
+        manifest_dir: &Path,
+        file: &Path,
+        (ident, typ): &(&::syn::Ident, &::syn::Type),
+        is_expected: bool,
+    ) -> Result<::syn::Expr> {
+        let span = self.pattern.span();
+        let (contents, optional) = Contents::of(typ).ok_or_else(|| {
+            ::syn::Error::new(
+                span,
+                format_args!(
+                    "Unsupported type `{}` for parameter `{}`; expected `&Path`, `PathBuf`, `&str`, `String`, `&[u8]` or `Vec<u8>`",
+                    typ.to_token_stream(),
+                    ident
+                ),
+            )
+        })?;
+
+        let full_path = manifest_dir.join(file);
+
+        if !full_path.is_file() {
+            if optional {
+                return Ok(::syn::parse_quote_spanned! { span=> ::core::option::Option::None });
+            }
+
+            let hint = if is_expected {
+                format!(", or use `Option<{}>`", typ.to_token_stream())
+            } else {
+                String::new()
+            };
+
+            return Err(::syn::Error::new(
+                span,
+                format_args!(
+                    "Missing file `{}` for parameter `{}`{}",
+                    file.display(),
+                    ident,
+                    hint
+                ),
+            ));
+        }
+
+        let relative = file.to_string_lossy();
+        let full_path = full_path.to_string_lossy();
+
+        let argument = match contents {
+            Contents::Path => ::quote::quote_spanned! { span=>
+                ::std::path::Path::new(::core::concat!(::core::env!("CARGO_MANIFEST_DIR"), "/", #relative))
+            },
+            Contents::PathBuf => ::quote::quote_spanned! { span=>
+                ::std::path::PathBuf::from(::core::concat!(::core::env!("CARGO_MANIFEST_DIR"), "/", #relative))
+            },
+            Contents::Str => ::quote::quote_spanned! { span=> ::core::include_str!(#full_path) },
+            Contents::String => ::quote::quote_spanned! { span=>
+                ::std::string::String::from(::core::include_str!(#full_path))
+            },
+            Contents::Bytes => {
+                ::quote::quote_spanned! { span=> ::core::include_bytes!(#full_path) as &[u8] }
+            }
+            Contents::ByteVec => ::quote::quote_spanned! { span=>
+                ::core::include_bytes!(#full_path).to_vec()
+            },
+        };
+
+        if optional {
+            Ok(::syn::parse_quote_spanned! { span=> ::core::option::Option::Some(#argument) })
+        } else {
+            ::syn::parse2(argument)
+        }
+    }
+}
+
+impl Parse for FileGlob {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(FileGlob {
+            keyword: input.parse()?,
+            assignment: input.parse()?,
+            pattern: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for FileGlob {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.keyword.to_tokens(tokens);
+        self.assignment.to_tokens(tokens);
+        self.pattern.to_tokens(tokens);
+    }
+}
+
+/// How a file is passed to a parameter.
+enum Contents {
+    Path,
+    PathBuf,
+    Str,
+    String,
+    Bytes,
+    ByteVec,
+}
+
+impl Contents {
+    /// How a file is passed to a parameter of the given type, and whether the type is an `Option`.
+    fn of(typ: &::syn::Type) -> Option<(Contents, bool)> {
+        let ident_of = |typ: &::syn::Type| match typ {
+            ::syn::Type::Path(path) => path.path.segments.last().map(|segment| {
+                let argument = match &segment.arguments {
+                    ::syn::PathArguments::AngleBracketed(arguments) => {
+                        match arguments.args.first() {
+                            Some(::syn::GenericArgument::Type(inner)) => Some(inner.clone()),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+
+                (segment.ident.to_string(), argument)
+            }),
+            _ => None,
+        };
+
+        let is_u8 =
+            |typ: &::syn::Type| matches!(typ, ::syn::Type::Path(path) if path.path.is_ident("u8"));
+
+        match typ {
+            ::syn::Type::Reference(reference) => match reference.elem.as_ref() {
+                ::syn::Type::Slice(slice) if is_u8(&slice.elem) => Some((Contents::Bytes, false)),
+                elem => match ident_of(elem)?.0.as_str() {
+                    "str" => Some((Contents::Str, false)),
+                    "Path" => Some((Contents::Path, false)),
+                    _ => None,
+                },
+            },
+            _ => match ident_of(typ)? {
+                (ident, Some(inner)) if ident == "Option" => Contents::of(&inner)
+                    .and_then(|(contents, optional)| (!optional).then(|| (contents, true))),
+                (ident, Some(inner)) if ident == "Vec" && is_u8(&inner) => {
+                    Some((Contents::ByteVec, false))
+                }
+                (ident, None) if ident == "String" => Some((Contents::String, false)),
+                (ident, None) if ident == "PathBuf" => Some((Contents::PathBuf, false)),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Collects the files below the directory which match the remaining components of the pattern,
+/// relative to the directory in which the search started.
+fn walk(directory: &Path, relative: PathBuf, pattern: &[&str], files: &mut Vec<PathBuf>) {
+    let (component, rest) = match pattern.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+
+    // `**` matches any number of directories, including none
+    if *component == "**" {
+        walk(directory, relative.clone(), rest, files);
+    }
+
+    let entries = match std::fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.filter_map(std::result::Result::ok) {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let path = entry.path();
+        let relative = relative.join(&name);
+
+        if *component == "**" {
+            if path.is_dir() {
+                walk(&path, relative, pattern, files);
+            }
+        } else if matches(component, &name) {
+            if rest.is_empty() {
+                if path.is_file() {
+                    files.push(relative);
+                }
+            } else if path.is_dir() {
+                walk(&path, relative, rest, files);
+            }
+        }
+    }
+}
+
+/// Whether a file name matches a component of a glob pattern, in which `*` matches any number
+/// of characters, and `?` matches a single character.
+fn matches(pattern: &str, name: &str) -> bool {
+    fn matches(pattern: &[char], name: &[char]) -> bool {
+        match (pattern.split_first(), name.split_first()) {
+            (None, None) => true,
+            (Some(('*', rest)), _) => {
+                matches(rest, name) || (!name.is_empty() && matches(pattern, &name[1..]))
+            }
+            (Some(('?', rest)), Some((_, name))) => matches(rest, name),
+            (Some((p, rest)), Some((n, name))) if p == n => matches(rest, name),
+            _ => false,
+        }
+    }
+
+    matches(
+        &pattern.chars().collect::<Vec<_>>(),
+        &name.chars().collect::<Vec<_>>(),
+    )
+}
@@ -0,0 +1,301 @@
+use crate::test_cases::TestCase;
+use quote::{format_ident, ToTokens};
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+/// The maximum number of test cases a range may generate.
+const MAX_RANGE_CASES: i128 = 1000;
+
+/// Test cases for a test function with a single parameter, generated from a list of values or
+/// from a range of integers.
+/// The syntax looks like this `values(1, 2, 3)`, like this `range(0..16)` or like this
+/// `range(0..=1000, step = 100)`.
+///
+/// The test cases of a list of values are named after the position of the value among the values
+/// of all lists of the test function, e.g. `value_0`, and those of a range after the value itself, e.g. `range_16`, or `range_minus_1` for `-1`.
+#[derive(Clone)]
+pub enum Generator {
+    Values {
+        keyword: ::syn::Ident,
+        parens: ::syn::token::Paren,
+        values: Punctuated<::syn::Expr, Token![,]>,
+    },
+    Range {
+        keyword: ::syn::Ident,
+        parens: ::syn::token::Paren,
+        range: ::syn::ExprRange,
+        step: Option<(Token![,], ::syn::Ident, Token![=], ::syn::LitInt)>,
+    },
+}
+
+impl Generator {
+    /// Whether the input starts with `values(` or `range(`.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        match fork.parse::<::syn::Ident>() {
+            Ok(keyword) => {
+                (keyword == "values" || keyword == "range") && fork.peek(::syn::token::Paren)
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// A test case for each value. The values of a list are numbered from `next_value` onwards.
+    pub fn test_cases(
+        &self,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+        next_value: &mut usize,
+    ) -> Result<Vec<TestCase>> {
+        let keyword = match self {
+            Generator::Values { keyword, .. } | Generator::Range { keyword, .. } => keyword,
+        };
+
+        if parameters.len() != 1 {
+            return Err(::syn::Error::new(
+                keyword.span(),
+                format_args!(
+                    "`{}(...)` generates test cases for a test function with a single parameter, but the test function has {} parameters",
+                    keyword,
+                    parameters.len()
+                ),
+            ));
+        }
+
+        match self {
+            Generator::Values {
+                keyword, values, ..
+            } => Ok(values
+                .iter()
+                .map(|value| {
+                    let id = format_ident!("value_{}", *next_value, span = keyword.span());
+                    *next_value += 1;
+
+                    TestCase::generated(id, std::iter::once(value.clone()).collect())
+                })
+                .collect()),
+            Generator::Range {
+                keyword,
+                range,
+                step,
+                ..
+            } => {
+                let (start, suffix) = bound(range.start.as_deref(), range)?;
+                let (end, end_suffix) = bound(range.end.as_deref(), range)?;
+                let suffix = if suffix.is_empty() {
+                    end_suffix
+                } else {
+                    suffix
+                };
+
+                // Overflows only for ranges which generate far too many test cases
+                let end = match range.limits {
+                    ::syn::RangeLimits::HalfOpen(_) => Some(end),
+                    ::syn::RangeLimits::Closed(_) => end.checked_add(1),
+                };
+
+                let step = match step {
+                    Some((_, _, _, step)) => match step.base10_parse::<i128>() {
+                        Ok(value) if value > 0 => value,
+                        _ => {
+                            return Err(::syn::Error::new(
+                                step.span(),
+                                "The step must be a positive integer",
+                            ))
+                        }
+                    },
+                    None => 1,
+                };
+
+                let count = match end {
+                    Some(end) if end > start => end
+                        .checked_sub(start)
+                        .and_then(|length| length.checked_add(step - 1))
+                        .map(|length| length / step),
+                    Some(_) => Some(0),
+                    None => None,
+                };
+
+                let count = match count {
+                    Some(0) => return Err(::syn::Error::new(range.span(), "The range is empty")),
+                    Some(count) if count <= MAX_RANGE_CASES => count,
+                    Some(count) => {
+                        return Err(::syn::Error::new(
+                            range.span(),
+                            format_args!(
+                                "The range generates {} test cases, but at most {} are supported; use a larger step",
+                                count, MAX_RANGE_CASES
+                            ),
+                        ))
+                    }
+                    None => {
+                        return Err(::syn::Error::new(
+                            range.span(),
+                            format_args!(
+                                "The range generates more than {} test cases, but at most {} are supported; use a larger step",
+                                i128::MAX, MAX_RANGE_CASES
+                            ),
+                        ))
+                    }
+                };
+
+                let span = keyword.span();
+
+                Ok((0..count)
+                    .map(|index| {
+                        let value = start + index * step;
+                        // The literal is spanned at the keyword, since lints which look at the
+                        // source of a literal would otherwise look at one of the bounds
+                        let literal = ::syn::LitInt::new(
+                            &format!("{}{}", value.unsigned_abs(), suffix),
+                            span,
+                        );
+
+                        let (id, argument): (_, ::syn::Expr) = if value < 0 {
+                            (
+                                format_ident!("range_minus_{}", value.unsigned_abs(), span = span),
+                                ::syn::parse_quote! { -#literal },
+                            )
+                        } else {
+                            (
+                                format_ident!("range_{}", value.unsigned_abs(), span = span),
+                                ::syn::parse_quote! { #literal },
+                            )
+                        };
+
+                        TestCase::generated(id, std::iter::once(argument).collect())
+                    })
+                    .collect())
+            }
+        }
+    }
+}
+
+/// The value and type suffix of a bound of a range, which must be an integer literal.
+fn bound(bound: Option<&::syn::Expr>, range: &::syn::ExprRange) -> Result<(i128, String)> {
+    let invalid = |span| {
+        ::syn::Error::new(
+            span,
+            "Expected an integer literal; ranges must have a start and an end, e.g. `range(0..16)`",
+        )
+    };
+
+    let (negative, literal) = match bound {
+        Some(::syn::Expr::Lit(::syn::ExprLit {
+            lit: ::syn::Lit::Int(literal),
+            ..
+        })) => (false, literal),
+        Some(::syn::Expr::Unary(::syn::ExprUnary {
+            op: ::syn::UnOp::Neg(_),
+            expr,
+            ..
+        })) => match expr.as_ref() {
+            ::syn::Expr::Lit(::syn::ExprLit {
+                lit: ::syn::Lit::Int(literal),
+                ..
+            }) => (true, literal),
+            _ => return Err(invalid(expr.span())),
+        },
+        Some(expr) => return Err(invalid(expr.span())),
+        None => return Err(invalid(range.span())),
+    };
+
+    let value = literal.base10_parse::<i128>()?;
+
+    Ok((
+        if negative { -value } else { value },
+        literal.suffix().to_string(),
+    ))
+}
+
+impl Parse for Generator {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword = input.parse::<::syn::Ident>()?;
+        let content;
+        let parens = parenthesized!(content in input);
+
+        if keyword == "values" {
+            return Ok(Generator::Values {
+                keyword,
+                parens,
+                values: Punctuated::parse_terminated(&content)?,
+            });
+        }
+
+        let range = match content.parse::<::syn::Expr>()? {
+            ::syn::Expr::Range(range) => range,
+            expr => {
+                return Err(::syn::Error::new(
+                    expr.span(),
+                    "Expected a range, e.g. `range(0..16)` or `range(0..=1000, step = 100)`",
+                ))
+            }
+        };
+
+        let step = match content.parse::<Option<Token![,]>>()? {
+            Some(comma) if !content.is_empty() => {
+                let name = content.parse::<::syn::Ident>()?;
+
+                if name != "step" {
+                    return Err(::syn::Error::new(
+                        name.span(),
+                        "Expected `step = ...`, e.g. `range(0..=1000, step = 100)`",
+                    ));
+                }
+
+                let step = Some((comma, name, content.parse()?, content.parse()?));
+
+                // Allow a trailing comma
+                content.parse::<Option<Token![,]>>()?;
+
+                step
+            }
+            _ => None,
+        };
+
+        if !content.is_empty() {
+            return Err(content.error("Unexpected input after the range"));
+        }
+
+        Ok(Generator::Range {
+            keyword,
+            parens,
+            range,
+            step,
+        })
+    }
+}
+
+impl ToTokens for Generator {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        match self {
+            Generator::Values {
+                keyword,
+                parens,
+                values,
+            } => {
+                keyword.to_tokens(tokens);
+                parens.surround(tokens, |tokens| values.to_tokens(tokens));
+            }
+            Generator::Range {
+                keyword,
+                parens,
+                range,
+                step,
+            } => {
+                keyword.to_tokens(tokens);
+                parens.surround(tokens, |tokens| {
+                    range.to_tokens(tokens);
+
+                    if let Some((comma, name, assignment, step)) = step {
+                        comma.to_tokens(tokens);
+                        name.to_tokens(tokens);
+                        assignment.to_tokens(tokens);
+                        step.to_tokens(tokens);
+                    }
+                });
+            }
+        }
+    }
+}
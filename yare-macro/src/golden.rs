@@ -0,0 +1,96 @@
+use crate::test_fn::TestFn;
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Result};
+
+/// The golden file of each test case, to which the body compares its actual output through
+/// `yare::golden!()`. The syntax looks like this `golden = "tests/golden/{fn}/{case}.txt"`,
+/// where `{fn}` is replaced by the name of the test function, and `{case}` by the id of the test
+/// case.
+#[derive(Clone)]
+pub struct GoldenFiles {
+    keyword: ::syn::Ident,
+    assignment: Token![=],
+    pattern: ::syn::LitStr,
+}
+
+impl GoldenFiles {
+    /// Whether the input starts with `golden = "`.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        match fork.parse::<::syn::Ident>() {
+            Ok(keyword) => keyword == "golden" && fork.peek(Token![=]) && fork.peek2(::syn::LitStr),
+            Err(_) => false,
+        }
+    }
+
+    /// The test function, with a body which defines the golden file of the running test case,
+    /// for `yare::golden!()`. The ids of all test cases are included, so golden files which
+    /// aren't used by any test case can be reported.
+    pub fn driver(&self, test_fn: &TestFn, ids: &[&::syn::Ident]) -> Result<TestFn> {
+        let pattern = self.pattern.value();
+        let mut rest = pattern.as_str();
+
+        // Only `{fn}` and `{case}` may be used as placeholders
+        while let Some(start) = rest.find('{') {
+            let placeholder = match rest[start..].find('}') {
+                Some(end) => &rest[start..=start + end],
+                None => &rest[start..],
+            };
+
+            if placeholder != "{fn}" && placeholder != "{case}" {
+                return Err(::syn::Error::new(
+                    self.pattern.span(),
+                    format_args!(
+                        "Unknown placeholder `{}`; expected `{{fn}}` or `{{case}}`",
+                        placeholder
+                    ),
+                ));
+            }
+
+            rest = &rest[start + placeholder.len()..];
+        }
+
+        if !pattern.contains("{case}") {
+            return Err(::syn::Error::new(
+                self.pattern.span(),
+                "Expected a golden file per test case, with `{case}` in the path, e.g. \"tests/golden/{fn}/{case}.txt\"",
+            ));
+        }
+
+        let pattern = pattern.replace("{fn}", &test_fn.identifier().to_string());
+        let body = test_fn.body();
+
+        Ok(test_fn.with_body(::syn::parse_quote! {
+            {
+                #[allow(dead_code)]
+                const __YARE_GOLDEN: ::yare::Golden = ::yare::Golden::__new(
+                    ::yare::case!(),
+                    ::core::env!("CARGO_MANIFEST_DIR"),
+                    #pattern,
+                    &[#(::core::stringify!(#ids)),*],
+                );
+
+                #body
+            }
+        }))
+    }
+}
+
+impl Parse for GoldenFiles {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(GoldenFiles {
+            keyword: input.parse()?,
+            assignment: input.parse()?,
+            pattern: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for GoldenFiles {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.keyword.to_tokens(tokens);
+        self.assignment.to_tokens(tokens);
+        self.pattern.to_tokens(tokens);
+    }
+}
@@ -7,19 +7,122 @@
 extern crate syn;
 extern crate proc_macro;
 
+mod bench;
+mod boundaries;
+mod case_set;
+mod cfg_select;
+mod compile_fail;
+mod const_eval;
+mod conversion;
+mod data_file;
+mod diagnostics;
+mod doc_table;
+mod extension;
+mod file_glob;
+mod generator;
+mod golden;
+mod options;
+mod rest;
+mod runner;
+mod spread;
+mod strategy;
 mod test_cases;
 mod test_fn;
+mod test_item;
+mod test_mod;
+mod type_cases;
+mod variants;
 
 #[proc_macro_attribute]
 pub fn parameterized(
     args: ::proc_macro::TokenStream,
     input: ::proc_macro::TokenStream,
 ) -> ::proc_macro::TokenStream {
-    let test_cases = parse_macro_input!(args as test_cases::TestCases);
-    let test_fn = parse_macro_input!(input as test_fn::TestFn);
+    expand(args.into(), input.into())
+        .unwrap_or_else(::syn::Error::into_compile_error)
+        .into()
+}
 
-    test_cases
-        .to_token_stream(&test_fn)
+// Shorthand for `#[parameterized(bench, ...)]`
+#[proc_macro_attribute]
+pub fn parameterized_bench(
+    args: ::proc_macro::TokenStream,
+    input: ::proc_macro::TokenStream,
+) -> ::proc_macro::TokenStream {
+    let args = ::proc_macro2::TokenStream::from(args);
+
+    expand(::quote::quote! { bench, #args }, input.into())
         .unwrap_or_else(::syn::Error::into_compile_error)
         .into()
 }
+
+#[proc_macro_attribute]
+pub fn parameterized_types(
+    args: ::proc_macro::TokenStream,
+    input: ::proc_macro::TokenStream,
+) -> ::proc_macro::TokenStream {
+    let type_cases = parse_macro_input!(args as type_cases::TypeCases);
+    let test_fn = parse_macro_input!(input as ::syn::ItemFn);
+
+    type_cases
+        .to_token_stream(test_fn)
+        .unwrap_or_else(::syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro]
+pub fn cases(input: ::proc_macro::TokenStream) -> ::proc_macro::TokenStream {
+    let case_sets = parse_macro_input!(input as case_set::CaseSets);
+
+    case_sets.to_token_stream().into()
+}
+
+#[proc_macro_derive(Variants)]
+pub fn derive_variants(input: ::proc_macro::TokenStream) -> ::proc_macro::TokenStream {
+    let input = parse_macro_input!(input as ::syn::DeriveInput);
+
+    variants::derive(input)
+        .unwrap_or_else(::syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(Spread)]
+pub fn derive_spread(input: ::proc_macro::TokenStream) -> ::proc_macro::TokenStream {
+    let input = parse_macro_input!(input as ::syn::DeriveInput);
+
+    spread::derive(input)
+        .unwrap_or_else(::syn::Error::into_compile_error)
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __resolve_cases(input: ::proc_macro::TokenStream) -> ::proc_macro::TokenStream {
+    let resolution = parse_macro_input!(input as case_set::Resolution);
+
+    resolution
+        .resolve()
+        .and_then(|(args, item)| expand(args, item))
+        .unwrap_or_else(::syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(
+    args: ::proc_macro2::TokenStream,
+    input: ::proc_macro2::TokenStream,
+) -> ::syn::Result<::proc_macro2::TokenStream> {
+    let test_cases = ::syn::parse2::<test_cases::TestCases>(args.clone())?;
+
+    // Referenced case sets are resolved one at a time, before the test item is expanded
+    if let Some(reference) = test_cases.first_case_set() {
+        return Ok(reference.to_callback(&args, &input));
+    }
+
+    let test_item = ::syn::parse2::<test_item::TestItem>(input.clone())?;
+
+    if let Some(reference) = test_item.first_case_set() {
+        return Ok(reference.to_callback(&args, &input));
+    }
+
+    test_item.to_token_stream(&test_cases)
+}
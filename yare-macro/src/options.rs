@@ -0,0 +1,126 @@
+use crate::diagnostics::Diagnostics;
+use crate::golden::GoldenFiles;
+use crate::runner::Harness;
+use crate::test_fn::{CaseGroup, TestFn};
+use std::borrow::Cow;
+
+/// The options of a test function, e.g. `runner` or `golden = "..."`. Like the test cases, the
+/// options of all its #[parameterized(...)] attributes end up in the same module, so each option
+/// applies to the test cases of all attributes.
+pub struct Options<'a> {
+    runner: Option<&'a ::syn::Ident>,
+    const_eval: Option<&'a ::syn::Ident>,
+    compile_fail: Option<&'a ::syn::Ident>,
+    bench: Option<&'a ::syn::Ident>,
+    golden: Option<&'a GoldenFiles>,
+}
+
+impl<'a> Options<'a> {
+    /// The options which were given to any of the #[parameterized(...)] attributes.
+    pub fn of(groups: &[CaseGroup<'a>]) -> Self {
+        let option = |name: &str| {
+            groups
+                .iter()
+                .find_map(|group| group.test_cases().option(name))
+        };
+
+        Options {
+            runner: option("runner"),
+            const_eval: option("const_eval"),
+            compile_fail: option("compile_fail"),
+            bench: option("bench"),
+            golden: groups.iter().find_map(|group| group.test_cases().golden()),
+        }
+    }
+
+    /// Checks whether the test function can be run as the options require. The `compile_fail`
+    /// option is checked when its driver is generated.
+    pub fn check(&self, test_fn: &TestFn) -> ::syn::Result<()> {
+        let mut diagnostics = Diagnostics::default();
+
+        if self.runner.is_some() {
+            diagnostics.take(crate::runner::check(test_fn));
+        }
+
+        if let Some(option) = self.const_eval {
+            diagnostics.take(crate::const_eval::check(test_fn, option));
+        }
+
+        if let Some(option) = self.bench {
+            diagnostics.take(crate::bench::check(
+                test_fn,
+                option,
+                &[self.runner, self.const_eval, self.compile_fail],
+            ));
+        }
+
+        diagnostics.finish(())
+    }
+
+    /// How the test cases of a group are run.
+    pub fn harness(&self, group: &CaseGroup) -> ::syn::Result<Harness> {
+        Harness::of(group, self.runner, self.bench)
+    }
+
+    /// The function which is expanded for each test case: the test function itself, or a driver
+    /// which compiles the source of the test case with the `compile_fail` option, and which knows
+    /// the golden files of the test cases with the `golden` option.
+    pub fn case_fn<'f>(
+        &self,
+        test_fn: &'f TestFn,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+        ids: &[&::syn::Ident],
+    ) -> ::syn::Result<Cow<'f, TestFn>> {
+        let mut diagnostics = Diagnostics::default();
+
+        let driver = self.compile_fail.and_then(|option| {
+            diagnostics.take(crate::compile_fail::driver(test_fn, parameters, option))
+        });
+
+        let golden_driver = self.golden.and_then(|golden| {
+            diagnostics.take(golden.driver(driver.as_ref().unwrap_or(test_fn), ids))
+        });
+
+        diagnostics.finish(
+            golden_driver
+                .or(driver)
+                .map_or(Cow::Borrowed(test_fn), Cow::Owned),
+        )
+    }
+
+    /// The constants which evaluate the test cases at compile time, with the `const_eval` option.
+    pub fn evaluations(&self, ids: &[&::syn::Ident]) -> Vec<::proc_macro2::TokenStream> {
+        match self.const_eval {
+            Some(_) => ids
+                .iter()
+                .map(|id| crate::const_eval::evaluation(id, self.runner.is_some()))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// The `#[cfg(test)]` attribute of the generated module. With the `runner` option, the test
+    /// cases are run by `yare::runner` from a test target without the default test harness, for
+    /// which `cfg(test)` isn't set. Likewise, benchmarks are run from a bench target.
+    pub fn cfg(&self) -> Option<::proc_macro2::TokenStream> {
+        match (self.runner, self.bench) {
+            (None, None) => Some(::quote::quote! { #[cfg(test)] }),
+            _ => None,
+        }
+    }
+
+    /// The function of the generated module which runs its test cases, if they're not run by the
+    /// default test harness: `benches()` with the `bench` option, or `trials()` with the
+    /// `runner` option.
+    pub fn trials(
+        &self,
+        test_fn: &TestFn,
+        ids: &[&::syn::Ident],
+    ) -> ::syn::Result<Option<::proc_macro2::TokenStream>> {
+        match (self.runner, self.bench) {
+            (_, Some(_)) => Ok(Some(crate::bench::group(ids))),
+            (Some(_), None) => crate::runner::trials(test_fn, ids).map(Some),
+            (None, None) => Ok(None),
+        }
+    }
+}
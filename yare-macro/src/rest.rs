@@ -0,0 +1,90 @@
+use syn::spanned::Spanned;
+
+/// The last parameter of a test function, when it's marked with `#[rest]`, e.g.
+/// `#[rest] items: Vec<&str>`. It collects the arguments of a test case which don't have a
+/// parameter of their own.
+pub enum Rest {
+    /// `Vec<T>`, collected with `vec![...]`
+    Vec,
+    /// `&[T]`, collected with `&[...]`
+    Slice,
+    /// `[T; N]`, collected with `[...]`
+    Array,
+}
+
+impl Rest {
+    /// Finds the parameter marked with `#[rest]`, which must be the last one.
+    pub fn find(
+        inputs: &::syn::punctuated::Punctuated<::syn::FnArg, Token![,]>,
+    ) -> ::syn::Result<Option<Rest>> {
+        let last = inputs.len().saturating_sub(1);
+        let mut rest = None;
+
+        for (index, input) in inputs.iter().enumerate() {
+            let (attrs, ty) = match input {
+                ::syn::FnArg::Typed(::syn::PatType { attrs, ty, .. }) => (attrs, ty),
+                ::syn::FnArg::Receiver(_) => continue,
+            };
+
+            let attribute = match attrs.iter().find(|attr| attr.path().is_ident("rest")) {
+                Some(attribute) => attribute,
+                None => continue,
+            };
+
+            if !matches!(attribute.meta, ::syn::Meta::Path(_)) {
+                return Err(::syn::Error::new(
+                    attribute.meta.span(),
+                    "Expected `#[rest]`, without arguments",
+                ));
+            }
+
+            if index != last {
+                return Err(::syn::Error::new(
+                    attribute.meta.span(),
+                    "Only the last parameter of a parameterized test function can be marked with `#[rest]`",
+                ));
+            }
+
+            rest = Some(Rest::from_type(ty)?);
+        }
+
+        Ok(rest)
+    }
+
+    fn from_type(ty: &::syn::Type) -> ::syn::Result<Rest> {
+        match ty {
+            ::syn::Type::Reference(::syn::TypeReference { elem, .. })
+                if matches!(elem.as_ref(), ::syn::Type::Slice(_)) =>
+            {
+                Ok(Rest::Slice)
+            }
+            ::syn::Type::Array(_) => Ok(Rest::Array),
+            ::syn::Type::Path(::syn::TypePath { qself: None, path })
+                if path
+                    .segments
+                    .last()
+                    .map_or(false, |segment| segment.ident == "Vec") =>
+            {
+                Ok(Rest::Vec)
+            }
+            _ => Err(::syn::Error::new(
+                ty.span(),
+                "Expected `Vec<T>`, `&[T]` or `[T; N]`; a `#[rest]` parameter collects the remaining arguments of a test case",
+            )),
+        }
+    }
+
+    /// The expression which collects the remaining arguments.
+    pub fn collect<'a>(
+        &self,
+        arguments: impl IntoIterator<Item = &'a ::syn::Expr>,
+    ) -> ::proc_macro2::TokenStream {
+        let arguments = arguments.into_iter();
+
+        match self {
+            Rest::Vec => ::quote::quote! { ::std::vec![#(#arguments),*] },
+            Rest::Slice => ::quote::quote! { &[#(#arguments),*] },
+            Rest::Array => ::quote::quote! { [#(#arguments),*] },
+        }
+    }
+}
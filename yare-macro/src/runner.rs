@@ -0,0 +1,153 @@
+use crate::diagnostics::Diagnostics;
+use crate::test_fn::{CaseGroup, TestFn};
+use syn::spanned::Spanned;
+
+/// How the generated test case functions are run.
+#[derive(Clone)]
+pub enum Harness {
+    /// By the default test harness, with a test macro like `#[test]`
+    TestMacro(Box<::syn::Meta>),
+    /// By `yare::runner`, which runs the trials returned by the `trials()` function of the
+    /// generated module
+    Runner,
+    /// By criterion, as benchmarks, which are run by the `benches()` function of the generated
+    /// module
+    Bench,
+}
+
+impl Harness {
+    /// The attribute which marks a generated function as a test.
+    pub fn test_attribute(&self) -> Option<::proc_macro2::TokenStream> {
+        match self {
+            Harness::TestMacro(meta) => Some(::quote::quote! { #[#meta] }),
+            Harness::Runner | Harness::Bench => None,
+        }
+    }
+
+    /// Whether an attribute of the test function is copied to the generated functions.
+    /// The runner handles `#[ignore]` and `#[should_panic]` itself, and benchmarks don't use them.
+    pub fn keeps(&self, attribute: &::syn::Attribute) -> bool {
+        match self {
+            Harness::TestMacro(_) => true,
+            Harness::Runner | Harness::Bench => {
+                !attribute.path().is_ident("ignore") && !attribute.path().is_ident("should_panic")
+            }
+        }
+    }
+}
+
+impl Harness {
+    /// How the test cases of a group are run: by the runner with the `runner` option, by criterion
+    /// with the `bench` option, or by the test macro of the group otherwise. The options can't be
+    /// used together with a #[test_macro(...)] attribute.
+    pub fn of(
+        group: &CaseGroup,
+        runner: Option<&::syn::Ident>,
+        bench: Option<&::syn::Ident>,
+    ) -> ::syn::Result<Harness> {
+        let (harness, option) = match (runner, bench) {
+            (_, Some(option)) => (Harness::Bench, option),
+            (Some(option), None) => (Harness::Runner, option),
+            (None, None) => {
+                return group
+                    .test_macro_attribute()
+                    .map(|test_meta| Harness::TestMacro(Box::new(test_meta)))
+            }
+        };
+
+        match group.test_macros().first() {
+            Some(test_macro) => Err(::syn::Error::new(
+                test_macro.span(),
+                format_args!(
+                    "A #[test_macro(...)] attribute can't be used together with the `{}` option",
+                    option
+                ),
+            )),
+            None => Ok(harness),
+        }
+    }
+}
+
+/// Checks whether the test function can be run by the runner, which only runs synchronous,
+/// safe functions.
+pub fn check(test_fn: &TestFn) -> ::syn::Result<()> {
+    let mut diagnostics = Diagnostics::default();
+
+    if let Some(asyncness) = test_fn.asyncness() {
+        diagnostics.push(::syn::Error::new(
+            asyncness.span(),
+            "The `runner` can't run async test functions; remove `runner`, or make the test function synchronous",
+        ));
+    }
+
+    if let Some(unsafety) = test_fn.unsafety() {
+        diagnostics.push(::syn::Error::new(
+            unsafety.span(),
+            "The `runner` can't run unsafe test functions; remove `runner`, or make the test function safe",
+        ));
+    }
+
+    diagnostics.finish(())
+}
+
+/// A `trials()` function, which returns a `yare::runner::Trial` for each generated test case.
+pub fn trials(
+    test_fn: &TestFn,
+    ids: &[&::syn::Ident],
+) -> ::syn::Result<::proc_macro2::TokenStream> {
+    let mut ignored = None;
+    let mut should_panic = None;
+
+    for attribute in test_fn.attributes() {
+        if attribute.path().is_ident("ignore") {
+            ignored = Some(::quote::quote! { .with_ignored_flag(true) });
+        } else if attribute.path().is_ident("should_panic") {
+            let expected = match &attribute.meta {
+                ::syn::Meta::Path(_) => None,
+                ::syn::Meta::List(list) => {
+                    let expected = list.parse_args_with(|input: ::syn::parse::ParseStream| {
+                        let name = input.parse::<::syn::Ident>()?;
+
+                        if name != "expected" {
+                            return Err(::syn::Error::new(
+                                name.span(),
+                                "expected `expected = \"...\"`",
+                            ));
+                        }
+
+                        input.parse::<Token![=]>()?;
+                        input.parse::<::syn::LitStr>()
+                    })?;
+
+                    Some(expected)
+                }
+                ::syn::Meta::NameValue(name_value) => Some(::syn::parse2::<::syn::LitStr>(
+                    ::quote::ToTokens::to_token_stream(&name_value.value),
+                )?),
+            };
+
+            let expected = match expected {
+                Some(expected) => ::quote::quote! { ::core::option::Option::Some(#expected) },
+                None => ::quote::quote! { ::core::option::Option::None },
+            };
+
+            should_panic = Some(::quote::quote! { .with_should_panic(#expected) });
+        }
+    }
+
+    Ok(::quote::quote! {
+        /// The test cases of this test function, to be run by `yare::runner::Runner`.
+        pub fn trials() -> ::std::vec::Vec<::yare::runner::Trial> {
+            ::std::vec![
+                #(
+                    ::yare::runner::Trial::test(
+                        ::yare::runner::__case_name(::core::module_path!(), ::core::stringify!(#ids)),
+                        #ids,
+                    )
+                    #ignored
+                    #should_panic
+                ),*
+            ]
+        }
+    })
+}
@@ -0,0 +1,176 @@
+use crate::conversion::Conversion;
+use quote::ToTokens;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+/// An argument which spreads a tuple, or a struct which derives `yare::Spread`, over consecutive
+/// parameters, e.g. `..RFC_EXAMPLE`. It binds to the parameters which aren't taken by the other
+/// arguments of the test case.
+pub struct Spread<'a> {
+    /// The position of the spread among the arguments of the test case
+    pub position: usize,
+    /// The number of parameters the tuple is spread over
+    pub count: usize,
+    tuple: &'a ::syn::Expr,
+}
+
+impl<'a> Spread<'a> {
+    /// Finds the argument which spreads a tuple, if any. A test case may have at most one.
+    ///
+    /// Since `..EXPR` is also a `RangeTo`, an argument is only a spread when the test case has fewer
+    /// or more arguments than the test function has parameters, and the test function doesn't have
+    /// a `#[rest]` parameter, which takes any number of arguments.
+    pub fn find(
+        id: &::syn::Ident,
+        arguments: &'a Punctuated<::syn::Expr, Token![,]>,
+        parameters: usize,
+        rest: bool,
+    ) -> ::syn::Result<Option<Spread<'a>>> {
+        if rest || arguments.len() == parameters {
+            return Ok(None);
+        }
+
+        let mut spreads = arguments
+            .iter()
+            .enumerate()
+            .filter_map(|(position, argument)| match argument {
+                ::syn::Expr::Range(::syn::ExprRange {
+                    start: None,
+                    limits: ::syn::RangeLimits::HalfOpen(_),
+                    end: Some(tuple),
+                    ..
+                }) => Some((position, argument, tuple.as_ref())),
+                _ => None,
+            });
+
+        let (position, argument, tuple) = match spreads.next() {
+            Some(spread) => spread,
+            None => return Ok(None),
+        };
+
+        if let Some((_, second, _)) = spreads.next() {
+            return Err(::syn::Error::new_spanned(
+                second,
+                format_args!(
+                    "{}: Expected at most one spread argument, e.g. `..TUPLE`, since the parameters it binds to would be ambiguous",
+                    id
+                ),
+            ));
+        }
+
+        let others = arguments.len() - 1;
+
+        if others >= parameters {
+            return Err(::syn::Error::new_spanned(
+                argument,
+                format_args!(
+                    "{}: The other {} arguments already bind to all {} parameters, so there are no parameters left to spread this tuple over",
+                    id, others, parameters
+                ),
+            ));
+        }
+
+        Ok(Some(Spread {
+            position,
+            count: parameters - others,
+            tuple,
+        }))
+    }
+
+    /// A source for each element of the tuple, e.g. `RFC_EXAMPLE.0`.
+    pub fn sources(&self) -> impl Iterator<Item = ::proc_macro2::TokenStream> + '_ {
+        (0..self.count).map(move |index| {
+            let tuple = self.tuple;
+            let index = ::syn::Index::from(index);
+
+            ::quote::quote! { ::core::stringify!(#tuple.#index) }
+        })
+    }
+
+    /// A destructuring `let` for the parameters the tuple is spread over, followed by the
+    /// conversion of the elements of converted parameters.
+    pub fn binding(
+        &self,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+        conversions: &[Option<Conversion>],
+    ) -> ::proc_macro2::TokenStream {
+        let tuple = self.tuple;
+        let count = self.count;
+        let idents = parameters.iter().map(|(ident, _)| ident);
+        let spread = ::syn::Ident::new("spread", ::proc_macro2::Span::mixed_site());
+
+        // The element of a converted parameter has the type of its argument
+        let types =
+            parameters
+                .iter()
+                .enumerate()
+                .map(|(index, (_, typ))| match conversions.get(index) {
+                    Some(Some(_)) => ::quote::quote! { _ },
+                    _ => typ.to_token_stream(),
+                });
+
+        let converted =
+            parameters
+                .iter()
+                .zip(conversions)
+                .filter_map(|((ident, typ), conversion)| {
+                    conversion.as_ref().map(|conversion| {
+                        let converted = conversion.convert(ident, typ, ident);
+
+                        ::quote::quote! { let #ident: #typ = #converted; }
+                    })
+                });
+
+        // The arity is checked separately from the element types, so a tuple with the wrong
+        // number of elements is reported as such
+        ::quote::quote_spanned! { tuple.span()=>
+            let #spread = ::yare::__private::spread::<_, #count>(#tuple);
+            let (#(#idents,)*): (#(#types,)*) = #spread;
+            #(#converted)*
+        }
+    }
+}
+
+/// `#[derive(Spread)]`, which implements `SpreadOverParameters<N>` for a struct with `N` fields, so
+/// a struct constant can be spread over parameters like a tuple, e.g. `..RFC_EXAMPLE`. The fields
+/// bind to the parameters in the order in which they're declared.
+pub fn derive(input: ::syn::DeriveInput) -> ::syn::Result<::proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        ::syn::Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(::syn::Error::new(
+                input.ident.span(),
+                "Spread can only be derived for structs",
+            ))
+        }
+    };
+
+    if fields.is_empty() {
+        return Err(::syn::Error::new(
+            input.ident.span(),
+            "Spread can only be derived for structs with fields",
+        ));
+    }
+
+    let name = &input.ident;
+    let count = fields.len();
+    let types = fields.iter().map(|field| &field.ty);
+    let members = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ::syn::Member::Named(ident.clone()),
+            None => ::syn::Member::Unnamed(::syn::Index::from(index)),
+        });
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(::quote::quote! {
+        impl #impl_generics ::yare::__private::SpreadOverParameters<#count> for #name #type_generics #where_clause {
+            type Tuple = (#(#types,)*);
+
+            fn spread(self) -> Self::Tuple {
+                (#(self.#members,)*)
+            }
+        }
+    })
+}
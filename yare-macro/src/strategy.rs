@@ -0,0 +1,254 @@
+use crate::diagnostics::Diagnostics;
+use crate::test_fn::TestFn;
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+/// The proptest strategies of a property-based test case, which replace its arguments.
+/// The syntax looks like this `strategy!(0..10u32, any::<bool>())`, with a strategy per parameter
+/// in the same order as the parameters, or like this `strategy!(flag in any::<bool>(), n in 0..10u32)`,
+/// with a strategy per parameter by name.
+#[derive(Clone)]
+pub struct Strategies {
+    path: ::syn::Path,
+    bang: Token![!],
+    parens: ::syn::token::Paren,
+    strategies: Punctuated<Strategy, Token![,]>,
+}
+
+#[derive(Clone)]
+struct Strategy {
+    parameter: Option<(::syn::Ident, Token![in])>,
+    expr: ::syn::Expr,
+}
+
+impl Strategies {
+    /// Whether the input starts with `strategy!`.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        match fork.parse::<::syn::Ident>() {
+            Ok(ident) => ident == "strategy" && fork.peek(Token![!]),
+            Err(_) => false,
+        }
+    }
+
+    /// The strategies, in the order of the parameters.
+    pub fn strategies<'a>(
+        &'a self,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+    ) -> Result<Vec<&'a ::syn::Expr>> {
+        let named = self
+            .strategies
+            .iter()
+            .any(|strategy| strategy.parameter.is_some());
+
+        if !named {
+            if self.strategies.len() != parameters.len() {
+                return Err(::syn::Error::new(
+                    self.parens.span.join(),
+                    format_args!(
+                        "Expected {} strategies, one for each parameter, but {} were given",
+                        parameters.len(),
+                        self.strategies.len()
+                    ),
+                ));
+            }
+
+            return Ok(self
+                .strategies
+                .iter()
+                .map(|strategy| &strategy.expr)
+                .collect());
+        }
+
+        let mut diagnostics = Diagnostics::default();
+
+        for strategy in self.strategies.iter() {
+            match &strategy.parameter {
+                Some((name, _)) if !parameters.iter().any(|(ident, _)| *ident == name) => {
+                    diagnostics.push(::syn::Error::new(
+                        name.span(),
+                        format_args!("Unknown parameter `{}`", name),
+                    ))
+                }
+                Some(_) => {}
+                None => diagnostics.push(::syn::Error::new(
+                    strategy.expr.span(),
+                    "Expected a named strategy, e.g. `name in 0..10`; strategies must either all be named, or none",
+                )),
+            }
+        }
+
+        let strategies = parameters
+            .iter()
+            .filter_map(|(ident, _)| {
+                let strategy = self.strategies.iter().find(
+                    |strategy| matches!(&strategy.parameter, Some((name, _)) if name == *ident),
+                );
+
+                if strategy.is_none() {
+                    diagnostics.push(::syn::Error::new(
+                        self.parens.span.close(),
+                        format_args!("Missing a strategy for parameter `{}`", ident),
+                    ));
+                }
+
+                strategy.map(|strategy| &strategy.expr)
+            })
+            .collect();
+
+        diagnostics.finish(strategies)
+    }
+
+    /// The body of a property-based test case, which runs the body of the test function with
+    /// values generated by the strategies.
+    pub fn generate_body(
+        &self,
+        test_fn: &TestFn,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+    ) -> Result<::proc_macro2::TokenStream> {
+        let mut diagnostics = Diagnostics::default();
+
+        if let Some(asyncness) = test_fn.asyncness() {
+            diagnostics.push(::syn::Error::new(
+                asyncness.span(),
+                "Strategies can't be used with async test functions",
+            ));
+        }
+
+        if let Some(constness) = test_fn.constness() {
+            diagnostics.push(::syn::Error::new(
+                constness.span(),
+                "Strategies can't be used with const test functions",
+            ));
+        }
+
+        if let Some(unsafety) = test_fn.unsafety() {
+            diagnostics.push(::syn::Error::new(
+                unsafety.span(),
+                "Strategies can't be used with unsafe test functions",
+            ));
+        }
+
+        let strategies = diagnostics.take(self.strategies(parameters));
+        let strategies = diagnostics.finish(strategies.unwrap_or_default())?;
+
+        let idents = parameters
+            .iter()
+            .map(|(ident, _)| ident)
+            .collect::<Vec<_>>();
+        let types = parameters.iter().map(|(_, typ)| typ).collect::<Vec<_>>();
+        let descriptions = parameters
+            .iter()
+            .map(|(ident, typ)| describe(typ, ::quote::quote! { #ident }));
+        let return_type = test_fn.return_type();
+        let body = test_fn.body();
+
+        let property = ::syn::Ident::new("property", ::proc_macro2::Span::mixed_site());
+
+        Ok(::quote::quote! {
+            fn #property(#(#idents: #types),*) #return_type #body
+
+            ::yare::__private::proptest::run(
+                ::yare::case!(),
+                (#(#strategies,)*),
+                |(#(#idents,)*): &(#(#types,)*)| ::std::vec![#(#descriptions),*],
+                |(#(#idents,)*): (#(#types,)*)| ::yare::__private::proptest::Outcome::into_test_case_result(#property(#(#idents),*)),
+            );
+        })
+    }
+
+    /// The source of each strategy, for the description of the test case.
+    pub fn sources(&self) -> impl Iterator<Item = &::syn::Expr> {
+        self.strategies.iter().map(|strategy| &strategy.expr)
+    }
+}
+
+/// An expression which formats a generated value as an argument of a test case. Most values are
+/// formatted with `Debug`, but a `String` is converted from a string literal, and a `Vec` is
+/// created with `vec![...]`, since their `Debug` output has another type, e.g. `"abc"` or `[1, 2]`.
+fn describe(typ: &::syn::Type, value: ::proc_macro2::TokenStream) -> ::proc_macro2::TokenStream {
+    let segment = match typ {
+        ::syn::Type::Path(path) if path.qself.is_none() => path.path.segments.last(),
+        _ => None,
+    };
+
+    let element = segment.and_then(|segment| match &segment.arguments {
+        ::syn::PathArguments::AngleBracketed(arguments) if segment.ident == "Vec" => {
+            match arguments.args.first() {
+                Some(::syn::GenericArgument::Type(element)) if arguments.args.len() == 1 => {
+                    Some(element)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    });
+
+    match (segment, element) {
+        (_, Some(element)) => {
+            let element = describe(element, ::quote::quote! { element });
+
+            ::quote::quote! {
+                ::std::format!(
+                    "::std::vec![{}]",
+                    #value.iter().map(|element| #element).collect::<::std::vec::Vec<_>>().join(", ")
+                )
+            }
+        }
+        (Some(segment), None) if segment.ident == "String" && segment.arguments.is_empty() => {
+            ::quote::quote! { ::std::format!("{:?}.into()", #value) }
+        }
+        _ => ::quote::quote! { ::std::format!("{:?}", #value) },
+    }
+}
+
+impl Parse for Strategies {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+
+        Ok(Strategies {
+            path: input.parse()?,
+            bang: input.parse()?,
+            parens: parenthesized!(content in input),
+            strategies: Punctuated::parse_terminated(&content)?,
+        })
+    }
+}
+
+impl ToTokens for Strategies {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.path.to_tokens(tokens);
+        self.bang.to_tokens(tokens);
+        self.parens
+            .surround(tokens, |tokens| self.strategies.to_tokens(tokens));
+    }
+}
+
+impl Parse for Strategy {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let parameter = if input.peek(::syn::Ident) && input.peek2(Token![in]) {
+            Some((input.parse()?, input.parse()?))
+        } else {
+            None
+        };
+
+        Ok(Strategy {
+            parameter,
+            expr: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Strategy {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        if let Some((name, in_token)) = &self.parameter {
+            name.to_tokens(tokens);
+            in_token.to_tokens(tokens);
+        }
+
+        self.expr.to_tokens(tokens);
+    }
+}
@@ -1,16 +1,35 @@
-use crate::test_fn::TestFn;
-use quote::format_ident;
+use crate::boundaries::Boundaries;
+use crate::case_set::CaseSetReference;
+use crate::cfg_select::CfgSelect;
+use crate::conversion::Conversion;
+use crate::data_file::DataFile;
+use crate::diagnostics::Diagnostics;
+use crate::extension::Extension;
+use crate::file_glob::FileGlob;
+use crate::generator::Generator;
+use crate::golden::GoldenFiles;
+use crate::options::Options;
+use crate::rest::Rest;
+use crate::runner::Harness;
+use crate::spread::Spread;
+use crate::strategy::Strategies;
+use crate::test_fn::{CaseGroup, TestFn};
+use crate::variants::VariantCases;
+use quote::{format_ident, ToTokens};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt::Formatter;
 use syn::braced;
 use syn::parse::{Parse, ParseStream, Result};
 use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 
 /// An ordered list of attribute arguments, which consists of test cases which start with the name
 /// of the test case, followed by a list of arguments. The order of the argument is equal to the
-/// input of the function.
+/// input of the function. Test cases may also be included from a named case set.
 #[derive(Clone)]
 pub struct TestCases {
-    cases: Punctuated<TestCase, Token![,]>,
+    entries: Punctuated<Entry, Token![,]>,
 }
 
 impl TestCases {
@@ -18,29 +37,156 @@ impl TestCases {
         let visibility = test_fn.visibility();
         let mod_ident = format_ident!("{}", test_fn.identifier());
 
-        let generated_cases = self
-            .cases
+        // Errors in the signature apply to every test case, so they're reported just once
+        let parameters = test_fn.parameters()?;
+        let rest = test_fn.rest()?;
+        let conversions = test_fn.conversions()?;
+
+        let mut diagnostics = Diagnostics::default();
+        let mut items = Vec::new();
+
+        let groups = test_fn.case_groups(self);
+        let options = Options::of(&groups);
+        diagnostics.take(options.check(test_fn));
+
+        let cases = collect(
+            test_fn,
+            &groups,
+            &options,
+            &parameters,
+            &mut items,
+            &mut diagnostics,
+        );
+        let cases = crate::extension::resolve_all(cases, &parameters, &mut diagnostics);
+
+        let ids = cases.iter().map(|(_, case)| case.id()).collect::<Vec<_>>();
+        let case_fn = diagnostics
+            .take(options.case_fn(test_fn, &parameters, &ids))
+            .unwrap_or(Cow::Borrowed(test_fn));
+
+        let generated_cases = cases
             .iter()
-            .map(|case| case.to_token_stream(test_fn))
-            .collect::<Result<Vec<_>>>()?;
+            .enumerate()
+            .filter_map(|(index, (harness, case))| {
+                diagnostics.take(case.as_ref().to_token_stream(
+                    &case_fn,
+                    &parameters,
+                    rest.as_ref(),
+                    &conversions,
+                    harness,
+                    index,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        diagnostics.take(check_unique_ids(&cases));
+
+        let cfg = options.cfg();
+        let evaluations = options.evaluations(&ids);
+        let trials = diagnostics.take(options.trials(test_fn, &ids)).flatten();
+
+        diagnostics.finish(())?;
 
         Ok(::quote::quote! {
-            #[cfg(test)]
+            #cfg
             #visibility mod #mod_ident {
                 use super::*;
 
+                #(#items)*
+
                 #(#generated_cases)*
+
+                #(#evaluations)*
+
+                #trials
             }
         })
     }
 }
 
+/// The test cases of the groups, in order, together with the harness which runs them. Entries may
+/// also add items to the generated module, e.g. to track the files from which the test cases are
+/// read.
+fn collect<'a>(
+    test_fn: &TestFn,
+    groups: &[CaseGroup<'a>],
+    options: &Options,
+    parameters: &[(&::syn::Ident, &::syn::Type)],
+    items: &mut Vec<::proc_macro2::TokenStream>,
+    diagnostics: &mut Diagnostics,
+) -> Vec<(Harness, Cow<'a, TestCase>)> {
+    let mut cases = Vec::new();
+    // The values of all `values(...)` generators are numbered in order, so their ids are unique
+    let mut next_value = 0;
+
+    for group in groups.iter() {
+        let harness = match diagnostics.take(options.harness(group)) {
+            Some(harness) => harness,
+            None => continue,
+        };
+
+        for entry in group.test_cases().entries.iter() {
+            let entry_cases = entry.test_cases(test_fn, parameters, &mut next_value, items);
+
+            cases.extend(
+                diagnostics
+                    .take(entry_cases)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|case| (harness.clone(), case)),
+            );
+        }
+
+        // Test cases given as #[case(...)] attributes follow those of the attribute itself
+        cases.extend(
+            group
+                .cases()
+                .iter()
+                .map(|case| (harness.clone(), Cow::Borrowed(*case))),
+        );
+    }
+
+    cases
+}
+
+/// Checks that the ids of the test cases are unique, since the test cases of all
+/// #[parameterized(...)] attributes end up in the same module.
+fn check_unique_ids(cases: &[(Harness, Cow<TestCase>)]) -> Result<()> {
+    let mut diagnostics = Diagnostics::default();
+    let mut ids = HashMap::<String, ::proc_macro2::Span>::new();
+
+    for (_, case) in cases {
+        if let Some(first) = ids.insert(case.id.to_string(), case.id.span()) {
+            diagnostics.push(::syn::Error::new(
+                case.id.span(),
+                format_args!("Duplicate test case id `{}`", case.id),
+            ));
+            diagnostics.push(::syn::Error::new(
+                first,
+                format_args!("First use of test case id `{}`", case.id),
+            ));
+        }
+    }
+
+    diagnostics.finish(())
+}
+
 impl std::fmt::Debug for TestCases {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str("TestCases(")?;
 
-        for case in self.cases.iter() {
-            case.fmt(f)?;
+        for entry in self.entries.iter() {
+            match entry {
+                Entry::Case(case) => case.fmt(f)?,
+                Entry::File(_) => f.write_str("DataFile")?,
+                Entry::Files(_) => f.write_str("FileGlob")?,
+                Entry::Boundaries(_) => f.write_str("Boundaries")?,
+                Entry::Variants(_) => f.write_str("Variants")?,
+                Entry::Generator(_) => f.write_str("Generator")?,
+                Entry::Option(option) => option.fmt(f)?,
+                Entry::Golden(_) => f.write_str("Golden")?,
+                Entry::CaseSet(reference) => reference.fmt(f)?,
+            }
         }
 
         f.write_str(")")
@@ -49,10 +195,219 @@ impl std::fmt::Debug for TestCases {
 
 impl Parse for TestCases {
     fn parse(input: ParseStream) -> Result<Self> {
-        Ok(TestCases {
-            cases: Punctuated::parse_terminated(input)?,
+        let mut diagnostics = Diagnostics::default();
+        let mut entries = Punctuated::new();
+
+        while !input.is_empty() {
+            let entry = input.parse::<Entry>().and_then(|entry| {
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+
+                Ok(entry)
+            });
+
+            match entry {
+                Ok(entry) => entries.push(entry),
+                Err(error) => {
+                    diagnostics.push(error);
+
+                    // Skip the remainder of the invalid entry, so the next entries can be checked too
+                    while !input.is_empty() && !input.peek(Token![,]) {
+                        input.parse::<::proc_macro2::TokenTree>()?;
+                    }
+
+                    input.parse::<Option<Token![,]>>()?;
+                }
+            }
+        }
+
+        diagnostics.finish(TestCases { entries })
+    }
+}
+
+impl TestCases {
+    /// The first referenced case set, which has not been resolved to its test cases yet.
+    pub fn first_case_set(&self) -> Option<&CaseSetReference> {
+        self.entries.iter().find_map(|entry| match entry {
+            Entry::CaseSet(reference) => Some(reference),
+            _ => None,
+        })
+    }
+
+    /// Replaces the first referenced case set with the given test cases.
+    pub fn resolve_first_case_set(&self, cases: TestCases) -> TestCases {
+        let mut cases = Some(cases);
+        let mut entries = Punctuated::new();
+
+        for entry in self.entries.iter() {
+            match (entry, cases.take()) {
+                // The variants of an enum are referred to by the path of the reference
+                (Entry::CaseSet(reference), Some(resolved)) if reference.is_variants() => entries
+                    .extend(resolved.entries.into_iter().map(|entry| match entry {
+                        Entry::Variants(variants) => {
+                            Entry::Variants(variants.with_path(reference.path()))
+                        }
+                        entry => entry,
+                    })),
+                (Entry::CaseSet(_), Some(resolved)) => entries.extend(resolved.entries),
+                (entry, unused) => {
+                    cases = unused;
+                    entries.push(entry.clone());
+                }
+            }
+        }
+
+        TestCases { entries }
+    }
+
+    /// The golden files of the test cases, if they were given, e.g.
+    /// `golden = "tests/golden/{fn}/{case}.txt"`.
+    pub fn golden(&self) -> Option<&GoldenFiles> {
+        self.entries.iter().find_map(|entry| match entry {
+            Entry::Golden(golden) => Some(golden),
+            _ => None,
         })
     }
+
+    /// The option with the given name, if it was given, e.g. `runner`.
+    pub fn option(&self, name: &str) -> Option<&::syn::Ident> {
+        self.entries.iter().find_map(|entry| match entry {
+            Entry::Option(option) if option == name => Some(option),
+            _ => None,
+        })
+    }
+}
+
+/// The options which may be given to a `#[parameterized(...)]` attribute, next to the test cases.
+const OPTIONS: &[&str] = &["runner", "doc_table", "const_eval", "compile_fail", "bench"];
+
+impl ToTokens for TestCases {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.entries.to_tokens(tokens);
+    }
+}
+
+/// A single attribute argument: either a test case, a data file with test cases, a glob pattern
+/// which matches a test case per file, the boundary values of a numeric parameter, the variants of
+/// an enum, a list or range of values, a reference to a named case set or to the variants of an
+/// enum, or an option.
+#[derive(Clone)]
+enum Entry {
+    Case(TestCase),
+    File(DataFile),
+    Files(FileGlob),
+    Boundaries(Boundaries),
+    Variants(VariantCases),
+    Generator(Generator),
+    CaseSet(CaseSetReference),
+    Option(::syn::Ident),
+    Golden(GoldenFiles),
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let fork = input.fork();
+        let is_option =
+            fork.parse::<::syn::Ident>().is_ok() && (fork.is_empty() || fork.peek(Token![,]));
+
+        if is_option {
+            let option = input.parse::<::syn::Ident>()?;
+
+            if !OPTIONS.iter().any(|name| option == name) {
+                return Err(::syn::Error::new(
+                    option.span(),
+                    format_args!(
+                        "Unknown option `{}`, expected one of: {}; or a test case, e.g. `{} = {{ ... }}`",
+                        option,
+                        OPTIONS.iter().map(|name| format!("`{}`", name)).collect::<Vec<_>>().join(", "),
+                        option,
+                    ),
+                ));
+            }
+
+            Ok(Entry::Option(option))
+        } else if GoldenFiles::peek(input) {
+            input.parse().map(Entry::Golden)
+        } else if DataFile::peek(input) {
+            input.parse().map(Entry::File)
+        } else if FileGlob::peek(input) {
+            input.parse().map(Entry::Files)
+        } else if Boundaries::peek(input) {
+            input.parse().map(Entry::Boundaries)
+        } else if Generator::peek(input) {
+            input.parse().map(Entry::Generator)
+        } else if VariantCases::peek(input) {
+            input.parse().map(Entry::Variants)
+        } else if input.peek(Token![use]) || CaseSetReference::peek_variants(input) {
+            input.parse().map(Entry::CaseSet)
+        } else {
+            input.parse().map(Entry::Case)
+        }
+    }
+}
+
+impl Entry {
+    /// The test cases of the entry. Entries which read the test cases from files, or which take
+    /// the variants of an enum, also add an item to the generated module, which tracks the files,
+    /// or which checks that every variant is covered.
+    fn test_cases(
+        &self,
+        test_fn: &TestFn,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+        next_value: &mut usize,
+        items: &mut Vec<::proc_macro2::TokenStream>,
+    ) -> Result<Vec<Cow<'_, TestCase>>> {
+        let cases = match self {
+            Entry::Case(case) => return Ok(vec![Cow::Borrowed(case)]),
+            Entry::File(file) => {
+                let mut diagnostics = Diagnostics::default();
+
+                items.extend(diagnostics.take(file.tracking()));
+                let cases = diagnostics.take(file.test_cases(parameters));
+
+                diagnostics.finish(cases.unwrap_or_default())?
+            }
+            Entry::Files(files) => {
+                items.push(files.tracking());
+                files.test_cases(parameters)?
+            }
+            Entry::Generator(generator) => generator.test_cases(parameters, next_value)?,
+            Entry::Variants(variants) => {
+                items.push(variants.exhaustiveness_check());
+                variants.test_cases()?
+            }
+            Entry::Boundaries(boundaries) => boundaries.test_cases(parameters)?,
+            Entry::CaseSet(reference) => {
+                return Err(::syn::Error::new(
+                    reference.span(),
+                    "Case set was not resolved",
+                ))
+            }
+            Entry::Option(option) if option == "doc_table" => {
+                crate::doc_table::test_cases(test_fn, parameters, option)?
+            }
+            Entry::Option(_) | Entry::Golden(_) => Vec::new(),
+        };
+
+        Ok(cases.into_iter().map(Cow::Owned).collect())
+    }
+}
+
+impl ToTokens for Entry {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        match self {
+            Entry::Case(case) => case.to_tokens(tokens),
+            Entry::File(file) => file.to_tokens(tokens),
+            Entry::Files(files) => files.to_tokens(tokens),
+            Entry::Boundaries(boundaries) => boundaries.to_tokens(tokens),
+            Entry::Variants(variants) => variants.to_tokens(tokens),
+            Entry::Generator(generator) => generator.to_tokens(tokens),
+            Entry::Option(option) => option.to_tokens(tokens),
+            Entry::Golden(golden) => golden.to_tokens(tokens),
+            Entry::CaseSet(reference) => reference.to_tokens(tokens),
+        }
+    }
 }
 
 /// The macro representation of a test case.
@@ -60,6 +415,12 @@ impl Parse for TestCases {
 /// Here the id is the name of a test case. The list of arguments, which is comma delimited and
 /// surrounded by brackets contains a list of arguments which will be supplied to the test function
 /// in the same order as provided here.
+///
+/// With the `proptest` feature, the arguments may instead be generated by a strategy per parameter,
+/// e.g. `id = strategy!(0..10u32, any::<bool>())`.
+///
+/// A test case may also extend another test case of the test function, and override the arguments
+/// of some of its parameters, e.g. `id = base { port = 0 }`.
 #[derive(Clone)]
 #[allow(dead_code)]
 pub struct TestCase {
@@ -67,15 +428,104 @@ pub struct TestCase {
     assignment: Token![=],
     braces: syn::token::Brace,
     arguments: Punctuated<syn::Expr, Token![,]>,
+    strategies: Option<Strategies>,
+    extension: Option<Extension>,
+    origin: Option<Origin>,
+}
+
+/// Where a test case which wasn't written in the attribute itself, e.g. a record of a data file,
+/// is defined, with the source of each of its arguments.
+#[derive(Clone)]
+pub struct Origin {
+    pub file: String,
+    pub line: usize,
+    pub sources: Vec<String>,
 }
 
 impl TestCase {
-    pub fn to_token_stream(&self, test_fn: &TestFn) -> Result<::proc_macro2::TokenStream> {
-        test_fn.assert_at_most_one_test_macro()?;
+    pub fn new(
+        id: ::syn::Ident,
+        arguments: Punctuated<::syn::Expr, Token![,]>,
+        origin: Origin,
+    ) -> Self {
+        TestCase {
+            origin: Some(origin),
+            ..TestCase::generated(id, arguments)
+        }
+    }
+
+    /// A test case which is given as an attribute of the test function, e.g.
+    /// `#[case(id, arg1, ..., argn)]`, where the parentheses take the place of the braces.
+    pub fn from_attribute(
+        id: ::syn::Ident,
+        parens: ::syn::token::Paren,
+        arguments: Punctuated<::syn::Expr, Token![,]>,
+    ) -> Self {
+        TestCase {
+            braces: ::syn::token::Brace { span: parens.span },
+            ..TestCase::generated(id, arguments)
+        }
+    }
+
+    /// A test case which is generated from the attribute, e.g. for a boundary value, and which is
+    /// reported at the span of its id.
+    pub fn generated(id: ::syn::Ident, arguments: Punctuated<::syn::Expr, Token![,]>) -> Self {
+        let span = id.span();
+
+        TestCase {
+            id,
+            assignment: Token![=](span),
+            braces: ::syn::token::Brace(span),
+            arguments,
+            strategies: None,
+            extension: None,
+            origin: None,
+        }
+    }
+
+    pub fn id(&self) -> &::syn::Ident {
+        &self.id
+    }
+
+    pub fn arguments(&self) -> &Punctuated<::syn::Expr, Token![,]> {
+        &self.arguments
+    }
+
+    /// The test case which this test case extends, if any, with its overridden arguments.
+    pub fn extension(&self) -> Option<&Extension> {
+        self.extension.as_ref()
+    }
 
-        let test_meta = test_fn.test_macro_attribute();
+    /// Whether the arguments of the test case are generated by proptest strategies.
+    pub fn is_property(&self) -> bool {
+        self.strategies.is_some()
+    }
+
+    /// This test case, with the given arguments instead of those of its extension.
+    pub fn with_arguments(&self, arguments: Punctuated<::syn::Expr, Token![,]>) -> Self {
+        TestCase {
+            arguments,
+            extension: None,
+            ..self.clone()
+        }
+    }
+
+    pub fn to_token_stream(
+        &self,
+        test_fn: &TestFn,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+        rest: Option<&Rest>,
+        conversions: &[Option<Conversion>],
+        harness: &Harness,
+        index: usize,
+    ) -> Result<::proc_macro2::TokenStream> {
+        // test attribute, e.g. #[test]
+        let test_attribute = harness.test_attribute();
         // fn attributes, e.g. #[require(x < 5)]
-        let attributes = test_fn.attributes();
+        let attributes = test_fn
+            .attributes()
+            .into_iter()
+            .filter(|attribute| harness.keeps(attribute));
         // fn visibility, e.g. pub, pub(in crate::some)
         let visibility = test_fn.visibility();
         // const qualifier
@@ -89,55 +539,345 @@ impl TestCase {
         // fn identifier, e.g. `hello` in `fn hello(a: i32) -> Option<()> { None }`
         let identifier = &self.id;
 
-        let bindings = self.generate_bindings(test_fn)?;
+        // The fields of a record of a data file are passed by name, also to a rest parameter, and
+        // have already been converted to the type of their parameter
+        let (rest, conversions) = match self.origin {
+            Some(_) => (None, &[][..]),
+            None => (rest, conversions),
+        };
 
-        // fn return type (output), e.g. `-> Option<()>` in `fn hello(a: i32) -> Option<()> { None }`
-        let return_type = test_fn.return_type();
+        let case_info = self.generate_case_info(test_fn, parameters, rest, index);
 
-        // fn block expression (function body), e.g. `{ None }` in `fn hello(a: i32) -> Option<()> { None }`
-        let body = test_fn.body();
+        // A benchmark runs the body of the test function many times, so it's measured without the
+        // failure context
+        if let Harness::Bench = harness {
+            if self.is_property() {
+                return Err(::syn::Error::new(
+                    identifier.span(),
+                    format_args!(
+                        "{}: A property-based test case can't be benchmarked, since its arguments are generated",
+                        identifier
+                    ),
+                ));
+            }
+
+            let bindings = self.generate_bindings(parameters, rest, conversions, None)?;
+
+            return Ok(crate::bench::function(
+                test_fn,
+                identifier,
+                parameters,
+                case_info,
+                bindings,
+                test_fn.body().to_token_stream(),
+            ));
+        }
+
+        // The failure context can't be used from a const fn, since its guard isn't const
+        let guard = ::syn::Ident::new("guard", ::proc_macro2::Span::mixed_site());
+        let guard = constness.is_none().then(|| &guard);
+
+        let context = guard.map(|guard| {
+            ::quote::quote! {
+                let #guard = ::yare::__private::CaseGuard::new(::yare::case!());
+            }
+        });
+
+        let (bindings, return_type, body) = match &self.strategies {
+            // The property returns the outcome of the test function, for each generated input
+            Some(strategies) => (
+                guard.map(|guard| ::quote::quote! { #guard.running(); }),
+                None,
+                strategies.generate_body(test_fn, parameters)?,
+            ),
+            None => (
+                Some(self.generate_bindings(parameters, rest, conversions, guard)?),
+                // fn return type (output), e.g. `-> Option<()>` in `fn hello(a: i32) -> Option<()> { None }`
+                Some(test_fn.return_type()),
+                // fn block expression (function body), e.g. `{ None }` in `fn hello(a: i32) -> Option<()> { None }`
+                test_fn.body().to_token_stream(),
+            ),
+        };
 
         Ok(::quote::quote! {
-            #[#test_meta]
+            #test_attribute
             #(#attributes)*
             #visibility #constness #asyncness #unsafety #abi fn #identifier() #return_type {
+                #case_info
+                #context
                 #bindings
                 #body
             }
         })
     }
 
-    fn generate_bindings(&self, test_fn: &TestFn) -> Result<::proc_macro2::TokenStream> {
+    /// Describes the test case, so it can be inspected with `yare::case!()` from the body of the
+    /// test function, and reported when the test case fails.
+    fn generate_case_info(
+        &self,
+        test_fn: &TestFn,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+        rest: Option<&Rest>,
+        index: usize,
+    ) -> ::proc_macro2::TokenStream {
         let identifier = &self.id;
+        let test_fn_identifier = test_fn.identifier();
+        let parameters = parameters.iter().map(|(ident, _)| ident);
 
-        // fn parameters, e.g. `a: i32` in `fn hello(a: i32) -> Option<()> { None }`
-        let parameters = test_fn.parameters()?;
+        let (arguments, location) = match &self.origin {
+            Some(origin) => {
+                let file = &origin.file;
+                let line = origin.line as u32;
+                let sources = origin
+                    .sources
+                    .iter()
+                    .map(|source| ::quote::quote! { #source });
 
-        if self.arguments.len() != parameters.len() {
-            return Err(::syn::Error::new(
-                identifier.span(), // Not ideal, but on stable, Span::call_site, or even an impl ToTokens for TestCase doesn't seem to include the whole test case, grrr!
-                format_args!(
-                    "{}: Expected {} arguments, but {} were given",
+                (
+                    sources.collect::<Vec<_>>(),
+                    ::quote::quote! { file: #file, line: #line, column: 1, },
+                )
+            }
+            None => {
+                let mut arguments = match &self.strategies {
+                    Some(strategies) => strategies
+                        .sources()
+                        .map(|source| ::quote::quote! { ::core::stringify!(#source) })
+                        .collect::<Vec<_>>(),
+                    None => self
+                        .arguments
+                        .iter()
+                        .map(|argument| ::quote::quote! { ::core::stringify!(#argument) })
+                        .collect(),
+                };
+
+                // The remaining arguments are described together, as the argument of the rest parameter
+                if let (Some(_), None) = (rest, &self.strategies) {
+                    let position = parameters.len().saturating_sub(1).min(arguments.len());
+                    let remaining = self.arguments.iter().skip(position);
+
+                    arguments.truncate(position);
+                    arguments.push(::quote::quote! { ::core::stringify!([#(#remaining),*]) });
+                }
+
+                // Each element of a spread tuple is described as the argument of its parameter
+                if let Ok(Some(spread)) = Spread::find(
                     identifier,
+                    &self.arguments,
                     parameters.len(),
-                    self.arguments.len(),
+                    rest.is_some(),
+                ) {
+                    if self.strategies.is_none() {
+                        arguments.splice(spread.position..=spread.position, spread.sources());
+                    }
+                }
+
+                (
+                    arguments,
+                    // The location macros take the span of the test case id, so they point at the attribute
+                    ::quote::quote_spanned! { identifier.span()=>
+                        file: ::core::file!(),
+                        line: ::core::line!(),
+                        column: ::core::column!(),
+                    },
+                )
+            }
+        };
+
+        ::quote::quote! {
+            #[allow(dead_code)]
+            const __YARE_CASE: ::yare::CaseInfo = ::yare::CaseInfo {
+                name: ::core::stringify!(#identifier),
+                index: #index,
+                test_fn: ::core::stringify!(#test_fn_identifier),
+                module_path: ::core::module_path!(),
+                args: &[#((::core::stringify!(#parameters), #arguments)),*],
+                #location
+            };
+        }
+    }
+
+    fn generate_bindings(
+        &self,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+        rest: Option<&Rest>,
+        conversions: &[Option<Conversion>],
+        guard: Option<&::syn::Ident>,
+    ) -> Result<::proc_macro2::TokenStream> {
+        let identifier = &self.id;
+        let given = self.arguments.len();
+
+        if let Some(spread) = Spread::find(
+            identifier,
+            &self.arguments,
+            parameters.len(),
+            rest.is_some(),
+        )? {
+            return Ok(self.generate_spread_bindings(parameters, &spread, conversions, guard));
+        }
+
+        // A rest parameter takes any number of arguments, including none
+        let (expected, at_least) = match rest {
+            Some(_) => (parameters.len().saturating_sub(1), "at least "),
+            None => (parameters.len(), ""),
+        };
+
+        if given > expected && rest.is_none() {
+            // Point at the first argument which doesn't have a matching parameter
+            return Err(::syn::Error::new(
+                self.arguments[expected].span(),
+                format_args!(
+                    "{}: Expected {} arguments, but {} were given; this argument has no matching parameter",
+                    identifier, expected, given,
+                ),
+            ));
+        }
+
+        if given < expected {
+            let missing = parameters[given..expected]
+                .iter()
+                .map(|(ident, _)| format!("`{}`", ident))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            // Point at the closing brace, where the missing arguments should be added
+            return Err(::syn::Error::new(
+                self.braces.span.close(),
+                format_args!(
+                    "{}: Expected {}{} arguments, but {} were given; missing an argument for {}",
+                    identifier, at_least, expected, given, missing,
                 ),
             ));
         }
 
+        if rest.is_some() {
+            let remaining = self.arguments.iter().skip(expected);
+
+            if let Some(select) = remaining
+                .map(ToTokens::to_token_stream)
+                .find(|argument| CfgSelect::from_argument(argument).is_some())
+            {
+                return Err(::syn::Error::new_spanned(
+                    select,
+                    format_args!(
+                        "{}: The arguments of a `#[rest]` parameter can't be selected with `cfg_select`",
+                        identifier,
+                    ),
+                ));
+            }
+        }
+
+        let arguments = self
+            .arguments
+            .iter()
+            .take(expected)
+            .map(ToTokens::to_token_stream);
+        let rest = rest.map(|rest| rest.collect(self.arguments.iter().skip(expected)));
+
         let bindings = parameters
             .iter()
-            .zip(&self.arguments)
-            .map(|((ident, typ), expr)| {
+            .zip(arguments.chain(rest))
+            .enumerate()
+            .map(|(position, ((ident, typ), expr))| {
+                let evaluating = guard.map(|guard| {
+                    ::quote::quote! { #guard.evaluating(::core::stringify!(#ident)); }
+                });
+                let binding = binding(
+                    ident,
+                    typ,
+                    expr,
+                    conversions.get(position).and_then(Option::as_ref),
+                );
+
                 ::quote::quote! {
-                    let #ident: #typ = #expr;
+                    #evaluating
+                    #binding
                 }
             });
 
+        let running = guard.map(|guard| ::quote::quote! { #guard.running(); });
+
         Ok(::quote::quote! {
             #(#bindings)*
+            #running
         })
     }
+
+    /// The bindings of a test case with an argument which spreads a tuple over the parameters which
+    /// aren't taken by its other arguments.
+    fn generate_spread_bindings(
+        &self,
+        parameters: &[(&::syn::Ident, &::syn::Type)],
+        spread: &Spread,
+        conversions: &[Option<Conversion>],
+        guard: Option<&::syn::Ident>,
+    ) -> ::proc_macro2::TokenStream {
+        let mut remaining = parameters;
+        let mut converted = conversions;
+
+        let bindings = self.arguments.iter().enumerate().map(|(position, expr)| {
+            let count = if position == spread.position {
+                spread.count
+            } else {
+                1
+            };
+            let (bound, rest) = remaining.split_at(count);
+            remaining = rest;
+            let (bound_conversions, rest) = converted.split_at(count.min(converted.len()));
+            converted = rest;
+
+            let idents = bound.iter().map(|(ident, _)| ident);
+            let evaluating = guard.map(|guard| {
+                ::quote::quote! { #guard.evaluating(::core::stringify!(#(#idents),*)); }
+            });
+
+            let binding = match bound {
+                [(ident, typ)] if position != spread.position => binding(
+                    ident,
+                    typ,
+                    expr.to_token_stream(),
+                    bound_conversions.first().and_then(Option::as_ref),
+                ),
+                _ => spread.binding(bound, bound_conversions),
+            };
+
+            ::quote::quote! {
+                #evaluating
+                #binding
+            }
+        });
+
+        let bindings = bindings.collect::<Vec<_>>();
+        let running = guard.map(|guard| ::quote::quote! { #guard.running(); });
+
+        ::quote::quote! {
+            #(#bindings)*
+            #running
+        }
+    }
+}
+
+/// Binds the argument of a parameter, converted if the parameter is marked with `#[from_str]` or
+/// `#[into]`. An argument with an alternative per configuration is bound once per alternative.
+fn binding(
+    ident: &::syn::Ident,
+    typ: &::syn::Type,
+    argument: ::proc_macro2::TokenStream,
+    conversion: Option<&Conversion>,
+) -> ::proc_macro2::TokenStream {
+    let bind = |argument: &dyn ToTokens| {
+        let argument = match conversion {
+            Some(conversion) => conversion.convert(ident, typ, argument),
+            None => argument.to_token_stream(),
+        };
+
+        ::quote::quote! { let #ident: #typ = #argument; }
+    };
+
+    match CfgSelect::from_argument(&argument) {
+        Some(select) => select.bindings(|value| bind(value)),
+        None => bind(&argument),
+    }
 }
 
 impl std::fmt::Debug for TestCase {
@@ -146,15 +886,89 @@ impl std::fmt::Debug for TestCase {
     }
 }
 
+impl ToTokens for TestCase {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.id.to_tokens(tokens);
+        self.assignment.to_tokens(tokens);
+
+        match (&self.strategies, &self.extension) {
+            (Some(strategies), _) => strategies.to_tokens(tokens),
+            (None, Some(extension)) => extension.to_tokens(tokens),
+            (None, None) => self
+                .braces
+                .surround(tokens, |tokens| self.arguments.to_tokens(tokens)),
+        }
+    }
+}
+
 impl Parse for TestCase {
     fn parse(input: ParseStream) -> Result<Self> {
+        let id = input.parse::<::syn::Ident>()?;
+
+        if input.peek(::syn::token::Brace) {
+            return Err(input.error(format_args!(
+                "expected `=` between the test case id and its arguments, e.g. `{} = {{ ... }}`",
+                id
+            )));
+        }
+
+        let assignment = input.parse()?;
+
+        if Strategies::peek(input) {
+            let strategies = input.parse::<Strategies>()?;
+
+            return Ok(TestCase {
+                braces: ::syn::token::Brace(id.span()),
+                id,
+                assignment,
+                arguments: Punctuated::new(),
+                strategies: Some(strategies),
+                extension: None,
+                origin: None,
+            });
+        }
+
+        if Extension::peek(input) {
+            let extension = input.parse::<Extension>()?;
+
+            return Ok(TestCase {
+                id,
+                assignment,
+                braces: extension.braces(),
+                arguments: Punctuated::new(),
+                strategies: None,
+                extension: Some(extension),
+                origin: None,
+            });
+        }
+
+        // A common mistake is to use another kind of delimiter for the arguments
+        if input.peek(::syn::token::Paren) || input.peek(::syn::token::Bracket) {
+            let group = input.parse::<::proc_macro2::Group>()?;
+
+            return Err(::syn::Error::new(
+                group.span_open(),
+                format_args!(
+                    "expected curly braces, e.g. `{} = {{ {} }}`",
+                    id,
+                    group.stream()
+                ),
+            ));
+        }
+
         let content;
 
         Ok(TestCase {
-            id: input.parse()?,
-            assignment: input.parse()?,
+            id,
+            assignment,
             braces: braced!(content in input),
-            arguments: Punctuated::parse_terminated(&content)?,
+            arguments: Punctuated::parse_terminated_with(
+                &content,
+                crate::cfg_select::parse_argument,
+            )?,
+            strategies: None,
+            extension: None,
+            origin: None,
         })
     }
 }
@@ -1,33 +1,67 @@
+use crate::case_set::CaseSetReference;
+use crate::conversion::Conversion;
+use crate::diagnostics::Diagnostics;
+use crate::rest::Rest;
+use crate::test_cases::{TestCase, TestCases};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 
+#[derive(Clone)]
 pub struct TestFn {
     attributes: Vec<Attribute>,
     fun: ::syn::ItemFn,
 }
 
 impl TestFn {
-    pub fn assert_at_most_one_test_macro(&self) -> ::syn::Result<()> {
-        let test_macros = self
+    /// The test cases of the `#[parameterized(...)]` attribute which is being expanded, followed by
+    /// the test cases of any additional `#[parameterized(...)]` attributes on this function.
+    /// Each group holds the `#[test_macro(...)]` and `#[case(...)]` attributes which follow its
+    /// `#[parameterized(...)]` attribute.
+    pub fn case_groups<'a>(&'a self, test_cases: &'a TestCases) -> Vec<CaseGroup<'a>> {
+        let mut groups = vec![CaseGroup::new(test_cases)];
+
+        for attribute in &self.attributes {
+            match attribute {
+                Attribute::TestMacro(meta) => {
+                    if let Some(group) = groups.last_mut() {
+                        group.test_macros.push(meta);
+                    }
+                }
+                Attribute::Case(case) => {
+                    if let Some(group) = groups.last_mut() {
+                        group.cases.push(case);
+                    }
+                }
+                Attribute::Parameterized(test_cases) => groups.push(CaseGroup::new(test_cases)),
+                Attribute::Normal(_) => {}
+            }
+        }
+
+        groups
+    }
+
+    /// Prepends the given `#[test_macro(...)]`, `#[case(...)]` and `#[parameterized(...)]`
+    /// attributes, e.g. of an enclosing module, to the ones of the additional
+    /// `#[parameterized(...)]` attributes of this function.
+    pub fn inherit(&mut self, attributes: &[Attribute]) {
+        let position = self
             .attributes
             .iter()
-            .filter_map(Attribute::to_test_macro)
-            .collect::<Vec<_>>();
+            .position(|attribute| matches!(attribute, Attribute::Parameterized(_)))
+            .unwrap_or(self.attributes.len());
 
-        let count = test_macros.len();
+        let inherited = attributes
+            .iter()
+            .filter(|attribute| !matches!(attribute, Attribute::Normal(_)))
+            .cloned();
 
-        if count <= 1 {
-            Ok(())
-        } else {
-            let meta = &test_macros[count - 1];
+        self.attributes.splice(position..position, inherited);
+    }
 
-            Err(::syn::Error::new(
-                meta.span(),
-                format_args!(
-                    "Expected at most 1 #[test_macro(...)] attribute, but {} were given",
-                    count
-                ),
-            ))
-        }
+    /// The first referenced case set of any additional `#[parameterized(...)]` attribute, which
+    /// has not been resolved to its test cases yet.
+    pub fn first_case_set(&self) -> Option<&CaseSetReference> {
+        Attribute::first_case_set(&self.attributes)
     }
 
     pub fn attributes(&self) -> Vec<::syn::Attribute> {
@@ -42,18 +76,6 @@ impl TestFn {
         parsed_attr
     }
 
-    pub fn test_macro_attribute(&self) -> ::syn::Meta {
-        self.attributes
-            .iter()
-            .find_map(Attribute::to_test_macro)
-            .unwrap_or_else(|| {
-                ::syn::Meta::Path(::syn::Path::from(::syn::Ident::new(
-                    "test",
-                    ::proc_macro2::Span::call_site(),
-                )))
-            })
-    }
-
     pub fn visibility(&self) -> &::syn::Visibility {
         &self.fun.vis
     }
@@ -79,22 +101,47 @@ impl TestFn {
     }
 
     pub fn parameters(&self) -> ::syn::Result<Vec<(&::syn::Ident, &::syn::Type)>> {
-        self.fun
+        let mut diagnostics = Diagnostics::default();
+
+        let parameters = self
+            .fun
             .sig
             .inputs
             .iter()
-            .map(|item| {
-                if let ::syn::FnArg::Typed(::syn::PatType { pat, ty, .. }) = item {
-                    if let ::syn::Pat::Ident(::syn::PatIdent { ident, .. }) = pat.as_ref() {
-                        Ok((ident, ty.as_ref()))
-                    } else {
-                        Err(::syn::Error::new(pat.span(), "Expected identifier"))
+            .filter_map(|item| match item {
+                ::syn::FnArg::Typed(::syn::PatType { pat, ty, .. }) => match pat.as_ref() {
+                    ::syn::Pat::Ident(::syn::PatIdent { ident, .. }) => Some((ident, ty.as_ref())),
+                    _ => {
+                        diagnostics.push(::syn::Error::new(
+                            pat.span(),
+                            "Expected identifier; patterns can't be used as parameters of a parameterized test function, use a name, e.g. `value`, instead",
+                        ));
+                        None
                     }
-                } else {
-                    Err(::syn::Error::new(item.span(), "Expected function argument"))
+                },
+                ::syn::FnArg::Receiver(receiver) => {
+                    diagnostics.push(::syn::Error::new(
+                        receiver.span(),
+                        "Expected function argument; a parameterized test function can't take a `self` receiver, remove it or move the test function out of the `impl` block",
+                    ));
+                    None
                 }
             })
-            .collect::<::syn::Result<_>>()
+            .collect::<Vec<_>>();
+
+        diagnostics.finish(parameters)
+    }
+
+    /// The last parameter, if it's marked with `#[rest]` to collect the remaining arguments of each
+    /// test case.
+    pub fn rest(&self) -> ::syn::Result<Option<Rest>> {
+        Rest::find(&self.fun.sig.inputs)
+    }
+
+    /// The conversion of the argument of each parameter, for parameters marked with `#[from_str]`
+    /// or `#[into]`.
+    pub fn conversions(&self) -> ::syn::Result<Vec<Option<Conversion>>> {
+        Conversion::find(&self.fun.sig.inputs)
     }
 
     pub fn return_type(&self) -> &::syn::ReturnType {
@@ -104,22 +151,20 @@ impl TestFn {
     pub fn body(&self) -> &::syn::Block {
         &self.fun.block
     }
+
+    /// This test function, with the given body instead of its own.
+    pub fn with_body(&self, body: ::syn::Block) -> TestFn {
+        let mut test_fn = self.clone();
+        test_fn.fun.block = Box::new(body);
+
+        test_fn
+    }
 }
 
 impl ::syn::parse::Parse for TestFn {
     fn parse(input: ::syn::parse::ParseStream) -> ::syn::parse::Result<Self> {
         Ok(TestFn {
-            attributes: input
-                .call(::syn::Attribute::parse_outer)?
-                .into_iter()
-                .map(|attr| {
-                    if attr.path().is_ident("test_macro") {
-                        attr.parse_args::<::syn::Meta>().map(Attribute::TestMacro)
-                    } else {
-                        Ok(Attribute::Normal(attr))
-                    }
-                })
-                .collect::<::syn::Result<Vec<_>>>()?,
+            attributes: Attribute::parse_all(input.call(::syn::Attribute::parse_outer)?)?,
             fun: input.parse()?,
         })
     }
@@ -139,25 +184,185 @@ impl ::std::fmt::Debug for TestFn {
     }
 }
 
-enum Attribute {
+/// The test cases of a single `#[parameterized(...)]` attribute, together with the
+/// `#[test_macro(...)]` attributes which apply to them, and the test cases of the `#[case(...)]`
+/// attributes which follow it.
+pub struct CaseGroup<'a> {
+    test_cases: &'a TestCases,
+    test_macros: Vec<&'a ::syn::Meta>,
+    cases: Vec<&'a TestCase>,
+}
+
+impl<'a> CaseGroup<'a> {
+    fn new(test_cases: &'a TestCases) -> Self {
+        CaseGroup {
+            test_cases,
+            test_macros: Vec::new(),
+            cases: Vec::new(),
+        }
+    }
+
+    pub fn test_cases(&self) -> &'a TestCases {
+        self.test_cases
+    }
+
+    /// The test cases of the `#[case(...)]` attributes which were given for this group.
+    pub fn cases(&self) -> &[&'a TestCase] {
+        &self.cases
+    }
+
+    /// The `#[test_macro(...)]` attributes which were given for this group.
+    pub fn test_macros(&self) -> &[&'a ::syn::Meta] {
+        &self.test_macros
+    }
+
+    pub fn test_macro_attribute(&self) -> ::syn::Result<::syn::Meta> {
+        let count = self.test_macros.len();
+
+        if count > 1 {
+            let meta = self.test_macros[count - 1];
+
+            return Err(::syn::Error::new(
+                meta.span(),
+                format_args!(
+                    "Expected at most 1 #[test_macro(...)] attribute, but {} were given",
+                    count
+                ),
+            ));
+        }
+
+        Ok(self.test_macros.first().map_or_else(
+            || {
+                ::syn::Meta::Path(::syn::Path::from(::syn::Ident::new(
+                    "test",
+                    ::proc_macro2::Span::call_site(),
+                )))
+            },
+            |meta| (*meta).clone(),
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub enum Attribute {
     /// A regular attribute, which isn't named "test_macro"
     Normal(::syn::Attribute),
     // An attribute named "test_macro"
     TestMacro(::syn::Meta),
+    // An additional attribute named "parameterized", of which the test cases will be merged
+    Parameterized(TestCases),
+    // A test case given as an attribute, e.g. `#[case(id, arg1, ..., argn)]`
+    Case(TestCase),
 }
 
 impl Attribute {
-    fn to_normal(&self) -> Option<::syn::Attribute> {
-        match self {
-            Attribute::Normal(inner) => Some(inner.clone()),
+    pub fn parse_all(attributes: Vec<::syn::Attribute>) -> ::syn::Result<Vec<Attribute>> {
+        let mut diagnostics = Diagnostics::default();
+
+        let attributes = attributes
+            .into_iter()
+            .filter_map(|attr| {
+                let attribute = if attr.path().is_ident("test_macro") {
+                    attr.parse_args::<::syn::Meta>().map(Attribute::TestMacro)
+                } else if is_parameterized(attr.path()) {
+                    // Without arguments, e.g. when its test cases are given as #[case(...)] attributes
+                    match &attr.meta {
+                        ::syn::Meta::Path(_) => {
+                            ::syn::parse2::<TestCases>(::proc_macro2::TokenStream::new())
+                        }
+                        _ => attr.parse_args::<TestCases>(),
+                    }
+                    .map(Attribute::Parameterized)
+                } else if is_case(attr.path()) {
+                    parse_case(&attr).map(Attribute::Case)
+                } else {
+                    Ok(Attribute::Normal(attr))
+                };
+
+                diagnostics.take(attribute)
+            })
+            .collect();
+
+        diagnostics.finish(attributes)
+    }
+
+    pub fn first_case_set(attributes: &[Attribute]) -> Option<&CaseSetReference> {
+        attributes.iter().find_map(|attribute| match attribute {
+            Attribute::Parameterized(test_cases) => test_cases.first_case_set(),
             _ => None,
-        }
+        })
     }
 
-    fn to_test_macro(&self) -> Option<::syn::Meta> {
+    pub fn to_normal(&self) -> Option<::syn::Attribute> {
         match self {
-            Attribute::TestMacro(inner) => Some(inner.clone()),
+            Attribute::Normal(inner) => Some(inner.clone()),
             _ => None,
         }
     }
 }
+
+/// Whether the path of an attribute refers to this macro, e.g. `parameterized` or
+/// `yare::parameterized`.
+pub fn is_parameterized(path: &::syn::Path) -> bool {
+    path.segments
+        .last()
+        .map_or(false, |segment| segment.ident == "parameterized")
+}
+
+/// Whether the path of an attribute is that of a test case, i.e. `case` or `case::id`.
+fn is_case(path: &::syn::Path) -> bool {
+    path.leading_colon.is_none()
+        && path
+            .segments
+            .first()
+            .map_or(false, |segment| segment.ident == "case")
+        && path.segments.len() <= 2
+}
+
+/// Parses a test case which is given as an attribute, which looks like
+/// `#[case(id, arg1, ..., argn)]` or `#[case::id(arg1, ..., argn)]`.
+fn parse_case(attribute: &::syn::Attribute) -> ::syn::Result<TestCase> {
+    let (list, parens) = match &attribute.meta {
+        ::syn::Meta::List(
+            list @ ::syn::MetaList {
+                delimiter: ::syn::MacroDelimiter::Paren(parens),
+                ..
+            },
+        ) => (list, *parens),
+        meta => {
+            return Err(::syn::Error::new(
+                meta.span(),
+                "Expected a test case, e.g. `#[case(id, arg1, ..., argn)]` or `#[case::id(arg1, ..., argn)]`",
+            ))
+        }
+    };
+
+    let named = list
+        .path
+        .segments
+        .iter()
+        .nth(1)
+        .map(|segment| segment.ident.clone());
+
+    list.parse_args_with(|input: ::syn::parse::ParseStream| {
+        let id = match named {
+            Some(id) => id,
+            None => {
+                let id = input.parse::<::syn::Ident>()?;
+
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+
+                id
+            }
+        };
+
+        let arguments = Punctuated::<::syn::Expr, Token![,]>::parse_terminated_with(
+            input,
+            crate::cfg_select::parse_argument,
+        )?;
+
+        Ok(TestCase::from_attribute(id, parens, arguments))
+    })
+}
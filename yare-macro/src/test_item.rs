@@ -0,0 +1,44 @@
+use crate::case_set::CaseSetReference;
+use crate::test_cases::TestCases;
+use crate::test_fn::TestFn;
+use crate::test_mod::TestMod;
+use syn::parse::{Parse, ParseStream, Result};
+
+/// The item a `#[parameterized(...)]` attribute was placed on: either a single test function, or
+/// an inline module of test functions which share the same test cases.
+pub enum TestItem {
+    Fn(Box<TestFn>),
+    Mod(TestMod),
+}
+
+impl TestItem {
+    pub fn to_token_stream(&self, test_cases: &TestCases) -> Result<::proc_macro2::TokenStream> {
+        match self {
+            TestItem::Fn(test_fn) => test_cases.to_token_stream(test_fn),
+            TestItem::Mod(test_mod) => test_mod.to_token_stream(test_cases),
+        }
+    }
+
+    /// The first referenced case set of an additional `#[parameterized(...)]` attribute, which
+    /// has not been resolved yet.
+    pub fn first_case_set(&self) -> Option<&CaseSetReference> {
+        match self {
+            TestItem::Fn(test_fn) => test_fn.first_case_set(),
+            TestItem::Mod(test_mod) => test_mod.first_case_set(),
+        }
+    }
+}
+
+impl Parse for TestItem {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let fork = input.fork();
+        fork.call(::syn::Attribute::parse_outer)?;
+        fork.parse::<::syn::Visibility>()?;
+
+        if fork.peek(Token![mod]) {
+            input.parse().map(TestItem::Mod)
+        } else {
+            input.parse().map(|test_fn| TestItem::Fn(Box::new(test_fn)))
+        }
+    }
+}
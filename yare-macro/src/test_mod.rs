@@ -0,0 +1,150 @@
+use crate::case_set::CaseSetReference;
+use crate::diagnostics::Diagnostics;
+use crate::test_cases::TestCases;
+use crate::test_fn::{Attribute, TestFn};
+use syn::parse::{Parse, ParseStream, Result};
+use syn::spanned::Spanned;
+
+/// An inline module, of which each function marked with `#[test]`, or another test macro like
+/// `#[tokio::test]`, is a test function which will be parameterized with the same set of test
+/// cases.
+pub struct TestMod {
+    attributes: Vec<Attribute>,
+    visibility: ::syn::Visibility,
+    mod_token: Token![mod],
+    identifier: ::syn::Ident,
+    items: Vec<ModItem>,
+}
+
+impl TestMod {
+    pub fn to_token_stream(&self, test_cases: &TestCases) -> Result<::proc_macro2::TokenStream> {
+        let attributes = self.attributes.iter().filter_map(Attribute::to_normal);
+        let visibility = &self.visibility;
+        let mod_token = &self.mod_token;
+        let identifier = &self.identifier;
+
+        let mut diagnostics = Diagnostics::default();
+
+        let items = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ModItem::TestFn(test_fn) => diagnostics.take(test_cases.to_token_stream(test_fn)),
+                ModItem::Other(other) => Some(::quote::quote! { #other }),
+            })
+            .collect::<Vec<_>>();
+
+        diagnostics.finish(())?;
+
+        Ok(::quote::quote! {
+            #(#attributes)*
+            #visibility #mod_token #identifier {
+                #(#items)*
+            }
+        })
+    }
+
+    /// The first referenced case set of any additional `#[parameterized(...)]` attribute, on
+    /// the module or on one of its test functions, which has not been resolved yet.
+    pub fn first_case_set(&self) -> Option<&CaseSetReference> {
+        Attribute::first_case_set(&self.attributes).or_else(|| {
+            self.items.iter().find_map(|item| match item {
+                ModItem::TestFn(test_fn) => test_fn.first_case_set(),
+                ModItem::Other(_) => None,
+            })
+        })
+    }
+}
+
+impl Parse for TestMod {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let item_mod = input.parse::<::syn::ItemMod>()?;
+
+        let content = match item_mod.content {
+            Some((_, content)) => content,
+            None => {
+                return Err(::syn::Error::new(
+                    item_mod.semi.span(),
+                    "Expected an inline module, e.g. `mod name { ... }`",
+                ))
+            }
+        };
+
+        let attributes = Attribute::parse_all(item_mod.attrs)?;
+
+        let mut diagnostics = Diagnostics::default();
+
+        let items = content
+            .into_iter()
+            .filter_map(|item| match item {
+                ::syn::Item::Fn(mut fun) => {
+                    // Only functions marked with a test attribute are test functions, others are
+                    // helpers
+                    let position = fun
+                        .attrs
+                        .iter()
+                        .position(|attribute| is_test(attribute.path()));
+
+                    let position = match position {
+                        Some(position) => position,
+                        None => return Some(ModItem::Other(::syn::Item::Fn(fun))),
+                    };
+
+                    // The test cases are marked as tests instead, by the test macro of the
+                    // function, e.g. `#[tokio::test]`, if it isn't `#[test]`
+                    let test_attribute = fun.attrs.remove(position);
+
+                    if !test_attribute.path().is_ident("test") {
+                        let test_meta = &test_attribute.meta;
+
+                        fun.attrs
+                            .insert(position, ::syn::parse_quote! { #[test_macro(#test_meta)] });
+                    }
+
+                    let mut test_fn =
+                        diagnostics.take(::syn::parse2::<TestFn>(::quote::quote! { #fun }))?;
+                    // Test macros and additional test cases of the module apply to each function
+                    test_fn.inherit(&attributes);
+
+                    Some(ModItem::TestFn(test_fn))
+                }
+                other => Some(ModItem::Other(other)),
+            })
+            .collect::<Vec<_>>();
+
+        diagnostics.finish(())?;
+
+        if !items.iter().any(|item| matches!(item, ModItem::TestFn(_))) {
+            return Err(::syn::Error::new(
+                item_mod.ident.span(),
+                format_args!(
+                    "Expected at least one test function in module `{}`, marked with #[test]",
+                    item_mod.ident
+                ),
+            ));
+        }
+
+        Ok(TestMod {
+            attributes,
+            visibility: item_mod.vis,
+            mod_token: item_mod.mod_token,
+            identifier: item_mod.ident,
+            items,
+        })
+    }
+}
+
+/// Whether the path of an attribute marks a test function, e.g. `test` or `tokio::test`.
+fn is_test(path: &::syn::Path) -> bool {
+    path.segments
+        .last()
+        .map_or(false, |segment| segment.ident == "test")
+}
+
+enum ModItem {
+    /// A function marked with `#[test]`, or another test macro like `#[tokio::test]`, which will be expanded into a module of parameterized test
+    /// cases
+    TestFn(TestFn),
+    /// Any other item, including helper functions, which is copied verbatim
+    Other(::syn::Item),
+}
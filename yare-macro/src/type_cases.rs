@@ -0,0 +1,278 @@
+use crate::diagnostics::Diagnostics;
+use quote::format_ident;
+use std::collections::HashMap;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+type Bounds = Punctuated<::syn::TypeParamBound, Token![+]>;
+
+/// The named types of a `#[parameterized_types(...)]` attribute, together with the trait bounds
+/// each of them must, or must not, implement. The syntax looks like this
+/// `requires = Send + Sync, forbids = Clone, config = Config, handle = Arc<State>`.
+pub struct TypeCases {
+    requires: Option<(::syn::Ident, Bounds)>,
+    forbids: Option<(::syn::Ident, Bounds)>,
+    cases: Vec<TypeCase>,
+}
+
+/// A single named type, e.g. `config = Config`.
+struct TypeCase {
+    id: ::syn::Ident,
+    typ: ::syn::Type,
+}
+
+impl TypeCases {
+    /// A module named after the test function, with compile-time assertions for the bounds of each
+    /// type, and a test for each type which calls the generic test function with that type.
+    ///
+    /// Unlike the test functions, the assertions aren't gated by `cfg(test)`, so a type which
+    /// doesn't meet its bounds already fails `cargo check`.
+    pub fn to_token_stream(&self, fun: ::syn::ItemFn) -> Result<::proc_macro2::TokenStream> {
+        let mut diagnostics = Diagnostics::default();
+
+        diagnostics.take(check_signature(&fun.sig));
+
+        if let Some((keyword, bounds)) = &self.forbids {
+            for bound in bounds {
+                if let ::syn::TypeParamBound::Lifetime(lifetime) = bound {
+                    diagnostics.push(::syn::Error::new(
+                        lifetime.span(),
+                        format_args!(
+                            "Expected a trait; `{}` only takes traits which the types must not implement",
+                            keyword
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let mut ids = HashMap::new();
+
+        for case in &self.cases {
+            if case.id == fun.sig.ident {
+                diagnostics.push(::syn::Error::new(
+                    case.id.span(),
+                    format_args!(
+                        "The type `{}` can't have the same name as the test function",
+                        case.id
+                    ),
+                ));
+            }
+
+            if let Some(first) = ids.insert(case.id.to_string(), case.id.span()) {
+                diagnostics.push(::syn::Error::new(
+                    case.id.span(),
+                    format_args!("Duplicate test case id `{}`", case.id),
+                ));
+                diagnostics.push(::syn::Error::new(
+                    first,
+                    format_args!("First use of test case id `{}`", case.id),
+                ));
+            }
+        }
+
+        diagnostics.finish(())?;
+
+        let assertions = self.cases.iter().map(|case| self.assertions(case));
+        let tests = self.cases.iter().map(|case| case.to_token_stream(&fun));
+
+        let visibility = &fun.vis;
+        let mod_ident = &fun.sig.ident;
+        let attributes = fun
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("doc"));
+
+        let mut generic_fn = fun.clone();
+        generic_fn.vis = ::syn::Visibility::Inherited;
+        generic_fn.attrs.clear();
+
+        Ok(::quote::quote! {
+            #(#attributes)*
+            #visibility mod #mod_ident {
+                use super::*;
+
+                #(#assertions)*
+
+                #[cfg(test)]
+                #generic_fn
+
+                #(#tests)*
+            }
+        })
+    }
+
+    /// Asserts at compile time that the type of a test case implements each required bound, and
+    /// none of the forbidden bounds. The assertions are spanned at the type, so a type which
+    /// doesn't meet its bounds is reported in the list of types.
+    fn assertions(&self, case: &TypeCase) -> ::proc_macro2::TokenStream {
+        let typ = &case.typ;
+
+        let requires = self.requires.as_ref().map(|(_, bounds)| {
+            ::quote::quote_spanned! { typ.span()=>
+                const _: fn() = || {
+                    fn requires<T: #bounds>() {}
+                    requires::<#typ>();
+                };
+            }
+        });
+
+        // A type which implements a forbidden trait matches both implementations, so the
+        // marker of the call can't be inferred, which is a compile error
+        let forbids = self.forbids.iter().flat_map(|(_, bounds)| {
+            bounds.iter().map(move |bound| {
+                // The error names this trait, e.g. `ImplementsForbiddenClone`
+                let name = match bound {
+                    ::syn::TypeParamBound::Trait(bound) => bound.path.segments.last(),
+                    _ => None,
+                };
+                let check = match name {
+                    Some(segment) => format_ident!("ImplementsForbidden{}", segment.ident),
+                    None => format_ident!("ImplementsForbiddenTrait"),
+                };
+
+                ::quote::quote_spanned! { typ.span()=>
+                    const _: fn() = || {
+                        trait #check<Marker> {
+                            fn forbidden() {}
+                        }
+                        impl<T> #check<()> for T {}
+                        struct Implemented;
+                        impl<T: #bound> #check<Implemented> for T {}
+                        let _ = <#typ as #check<_>>::forbidden;
+                    };
+                }
+            })
+        });
+
+        ::quote::quote! {
+            #requires
+            #(#forbids)*
+        }
+    }
+}
+
+impl Parse for TypeCases {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut diagnostics = Diagnostics::default();
+        let mut type_cases = TypeCases {
+            requires: None,
+            forbids: None,
+            cases: Vec::new(),
+        };
+
+        while !input.is_empty() {
+            let id = input.parse::<::syn::Ident>()?;
+            input.parse::<Token![=]>()?;
+
+            if id == "requires" || id == "forbids" {
+                let bounds = Bounds::parse_separated_nonempty(input)?;
+                let clause = if id == "requires" {
+                    &mut type_cases.requires
+                } else {
+                    &mut type_cases.forbids
+                };
+
+                if clause.is_some() {
+                    diagnostics.push(::syn::Error::new(
+                        id.span(),
+                        format_args!(
+                            "Expected at most one `{} = ...`; combine the bounds with `+` instead, e.g. `{} = Send + Sync`",
+                            id, id
+                        ),
+                    ));
+                }
+
+                *clause = Some((id, bounds));
+            } else {
+                type_cases.cases.push(TypeCase {
+                    id,
+                    typ: input.parse()?,
+                });
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+
+        if type_cases.cases.is_empty() {
+            diagnostics.push(::syn::Error::new(
+                input.span(),
+                "Expected at least one named type, e.g. `#[parameterized_types(requires = Send, config = Config)]`",
+            ));
+        }
+
+        diagnostics.finish(type_cases)
+    }
+}
+
+impl TypeCase {
+    /// A test which calls the generic test function with the type of this test case.
+    fn to_token_stream(&self, fun: &::syn::ItemFn) -> ::proc_macro2::TokenStream {
+        let attributes = fun
+            .attrs
+            .iter()
+            .filter(|attribute| !attribute.path().is_ident("doc"));
+        let visibility = &fun.vis;
+        let output = &fun.sig.output;
+        let generic_fn = &fun.sig.ident;
+        let id = &self.id;
+        let typ = &self.typ;
+
+        ::quote::quote! {
+            #[cfg(test)]
+            #[test]
+            #(#attributes)*
+            #visibility fn #id() #output {
+                #generic_fn::<#typ>()
+            }
+        }
+    }
+}
+
+/// Checks that the test function takes the type of each test case as its single type parameter,
+/// and no arguments.
+fn check_signature(signature: &::syn::Signature) -> Result<()> {
+    let mut diagnostics = Diagnostics::default();
+
+    let type_parameters = signature
+        .generics
+        .params
+        .iter()
+        .filter(|param| matches!(param, ::syn::GenericParam::Type(_)))
+        .count();
+
+    if type_parameters != 1 || signature.generics.params.len() != 1 {
+        diagnostics.push(::syn::Error::new(
+            signature.ident.span(),
+            "Expected a single type parameter, which takes each of the types, e.g. `fn thread_safe<T>()`",
+        ));
+    }
+
+    if !signature.inputs.is_empty() {
+        diagnostics.push(::syn::Error::new(
+            signature.inputs.span(),
+            "Expected no parameters; the test function is called once for each of the types",
+        ));
+    }
+
+    if let Some(asyncness) = signature.asyncness {
+        diagnostics.push(::syn::Error::new(
+            asyncness.span(),
+            "Expected a synchronous test function, since it's run by the built-in test macro",
+        ));
+    }
+
+    if let Some(unsafety) = signature.unsafety {
+        diagnostics.push(::syn::Error::new(
+            unsafety.span(),
+            "Expected a safe test function, since it's run by the built-in test macro",
+        ));
+    }
+
+    diagnostics.finish(())
+}
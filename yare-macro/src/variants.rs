@@ -0,0 +1,276 @@
+use crate::data_file::identifier;
+use crate::test_cases::TestCase;
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+/// Test cases for the variants of a fieldless enum.
+/// The syntax looks like this `variants = Fruit { Apple, Pear }`, or like this
+/// `variants = Fruit { Apple => "apple", Pear => { "pear", 3 } }` to pair each variant with the
+/// arguments for the other parameters of the test function.
+///
+/// The variant is passed to the first parameter of the test function, and the test case is named
+/// after the variant, e.g. `apple`. Since the generated code matches on the listed variants, a
+/// variant which isn't listed fails to compile.
+///
+/// A reference like `variants = Fruit`, to an enum which derives `yare::Variants`, resolves to
+/// this form, with all variants of the enum.
+#[derive(Clone)]
+pub struct VariantCases {
+    keyword: ::syn::Ident,
+    assignment: Token![=],
+    path: ::syn::Path,
+    braces: ::syn::token::Brace,
+    variants: Punctuated<Variant, Token![,]>,
+}
+
+/// A single variant, with the arguments for the other parameters of the test function.
+#[derive(Clone)]
+struct Variant {
+    ident: ::syn::Ident,
+    arguments: Option<(Token![=>], VariantArguments)>,
+}
+
+#[derive(Clone)]
+enum VariantArguments {
+    Single(::syn::Expr),
+    Braced(::syn::token::Brace, Punctuated<::syn::Expr, Token![,]>),
+}
+
+impl VariantCases {
+    /// Whether the input starts with `variants = Path {`.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        match fork.parse::<::syn::Ident>() {
+            Ok(keyword) if keyword == "variants" && fork.peek(Token![=]) => {
+                fork.parse::<Token![=]>().is_ok()
+                    && fork.parse::<::syn::Path>().is_ok()
+                    && fork.peek(::syn::token::Brace)
+            }
+            _ => false,
+        }
+    }
+
+    /// The variants of an enum, as referenced by the given path.
+    pub fn with_path(&self, path: &::syn::Path) -> Self {
+        VariantCases {
+            path: path.clone(),
+            ..self.clone()
+        }
+    }
+
+    /// A test case for each variant.
+    pub fn test_cases(&self) -> Result<Vec<TestCase>> {
+        let path = &self.path;
+
+        self.variants
+            .iter()
+            .map(|variant| {
+                let span = variant.ident.span();
+                let name = snake_case(&variant.ident.to_string());
+                let id = identifier(&name, span).map_err(|message| {
+                    ::syn::Error::new(span, format_args!("{} `{}`", message, variant.ident))
+                })?;
+
+                let ident = &variant.ident;
+                let mut arguments = Punctuated::<::syn::Expr, Token![,]>::new();
+                arguments.push(::syn::parse_quote_spanned! { span=> #path::#ident });
+
+                match &variant.arguments {
+                    Some((_, VariantArguments::Single(expr))) => arguments.push(expr.clone()),
+                    Some((_, VariantArguments::Braced(_, exprs))) => {
+                        arguments.extend(exprs.iter().cloned())
+                    }
+                    None => {}
+                }
+
+                Ok(TestCase::generated(id, arguments))
+            })
+            .collect()
+    }
+
+    /// A match on the listed variants, which fails to compile when a variant of the enum isn't
+    /// listed.
+    pub fn exhaustiveness_check(&self) -> ::proc_macro2::TokenStream {
+        let path = &self.path;
+        let variants = self.variants.iter().map(|variant| &variant.ident);
+
+        ::quote::quote_spanned! { path.span()=>
+            const _: () = {
+                #[allow(dead_code)]
+                fn exhaustive(value: &#path) {
+                    match *value {
+                        #(#path::#variants => {})*
+                    }
+                }
+            };
+        }
+    }
+}
+
+impl Parse for VariantCases {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+
+        Ok(VariantCases {
+            keyword: input.parse()?,
+            assignment: input.parse()?,
+            path: input.parse()?,
+            braces: braced!(content in input),
+            variants: Punctuated::parse_terminated(&content)?,
+        })
+    }
+}
+
+impl ToTokens for VariantCases {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.keyword.to_tokens(tokens);
+        self.assignment.to_tokens(tokens);
+        self.path.to_tokens(tokens);
+        self.braces
+            .surround(tokens, |tokens| self.variants.to_tokens(tokens));
+    }
+}
+
+impl Parse for Variant {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident = input.parse()?;
+        let arguments = if input.peek(Token![=>]) {
+            let arrow = input.parse()?;
+            let arguments = if input.peek(::syn::token::Brace) {
+                let content;
+
+                VariantArguments::Braced(
+                    braced!(content in input),
+                    Punctuated::parse_terminated(&content)?,
+                )
+            } else {
+                VariantArguments::Single(input.parse()?)
+            };
+
+            Some((arrow, arguments))
+        } else {
+            None
+        };
+
+        Ok(Variant { ident, arguments })
+    }
+}
+
+impl ToTokens for Variant {
+    fn to_tokens(&self, tokens: &mut ::proc_macro2::TokenStream) {
+        self.ident.to_tokens(tokens);
+
+        match &self.arguments {
+            Some((arrow, VariantArguments::Single(expr))) => {
+                arrow.to_tokens(tokens);
+                expr.to_tokens(tokens);
+            }
+            Some((arrow, VariantArguments::Braced(braces, exprs))) => {
+                arrow.to_tokens(tokens);
+                braces.surround(tokens, |tokens| exprs.to_tokens(tokens));
+            }
+            None => {}
+        }
+    }
+}
+
+/// `#[derive(Variants)]`, which implements `yare::Variants` for a fieldless enum, and defines a
+/// `macro_rules!` macro with the same name as the enum, through which `variants = Enum` in a
+/// `#[parameterized(...)]` attribute is resolved to the variants of the enum.
+pub fn derive(input: ::syn::DeriveInput) -> Result<::proc_macro2::TokenStream> {
+    let data = match &input.data {
+        ::syn::Data::Enum(data) => data,
+        _ => {
+            return Err(::syn::Error::new(
+                input.ident.span(),
+                "Variants can only be derived for enums",
+            ))
+        }
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(::syn::Error::new(
+            input.generics.span(),
+            "Variants can't be derived for generic enums",
+        ));
+    }
+
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, ::syn::Fields::Unit))
+    {
+        return Err(::syn::Error::new(
+            variant.fields.span(),
+            "Variants can only be derived for enums without fields",
+        ));
+    }
+
+    let visibility = &input.vis;
+    let name = &input.ident;
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| &variant.ident)
+        .collect::<Vec<_>>();
+    let macro_name =
+        crate::case_set::exported_name("__yare_variants", name, &::quote::quote! { #(#variants)* });
+
+    // Like case sets, only the macros of public enums are exported
+    let export = match visibility {
+        ::syn::Visibility::Public(_) => Some(::quote::quote! { #[macro_export] }),
+        _ => None,
+    };
+
+    Ok(::quote::quote! {
+        impl ::yare::Variants for #name {
+            const VARIANTS: &'static [Self] = &[#(#name::#variants),*];
+        }
+
+        #[doc(hidden)]
+        #export
+        macro_rules! #macro_name {
+            ($($state:tt)*) => {
+                ::yare::__resolve_cases! { { variants = #name { #(#variants),* } } $($state)* }
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        #visibility use #macro_name as #name;
+    })
+}
+
+/// Converts the name of a variant to snake case, e.g. `BlackBerry` to `black_berry`, and
+/// `HttpOK` or `HTTPOk` to `http_ok`.
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut snake = String::new();
+
+    for (index, c) in chars.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|index| chars[index]);
+        let next = chars.get(index + 1);
+
+        // A word starts at an uppercase letter after a lowercase letter or digit, or at the
+        // last uppercase letter of an acronym which is followed by a lowercase letter
+        let starts_word = c.is_uppercase()
+            && match previous {
+                Some(previous) if previous.is_uppercase() => {
+                    next.map_or(false, |next| next.is_lowercase())
+                }
+                Some(previous) => previous != '_',
+                None => false,
+            };
+
+        if starts_word {
+            snake.push('_');
+        }
+
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
@@ -0,0 +1,4 @@
+fn main() {
+    // Adds a test case when a fixture is added, see `src/file_glob.rs`
+    println!("cargo:rerun-if-changed=fixtures");
+}
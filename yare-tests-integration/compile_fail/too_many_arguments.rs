@@ -0,0 +1,6 @@
+use yare::parameterized;
+
+#[parameterized(one = { 1, 2 })]
+fn too_many_arguments(value: u8) {}
+
+fn main() {}
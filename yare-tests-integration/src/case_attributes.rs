@@ -0,0 +1,12 @@
+use yare::parameterized;
+
+#[parameterized]
+#[case(no_wait, 0)]
+#[case::short_wait(10)]
+#[test_macro(tokio::test(start_paused = true))]
+async fn with_test_macro(wait: u64) {
+    let start = tokio::time::Instant::now();
+    tokio::time::sleep(tokio::time::Duration::from_millis(wait)).await;
+
+    assert_eq!(start.elapsed().as_millis(), u128::from(wait));
+}
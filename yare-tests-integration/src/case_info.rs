@@ -0,0 +1,19 @@
+use yare::parameterized;
+
+#[parameterized(
+    case1 = { 0 },
+    case2 = { 1 },
+)]
+fn test(index: usize) {
+    assert_eq!(yare::case!().index, index);
+}
+
+#[parameterized(
+    case1 = { "case1" },
+)]
+#[test_macro(tokio::test)]
+async fn in_async_test(name: &str) {
+    tokio::task::yield_now().await;
+
+    assert_eq!(yare::case!().name, name);
+}
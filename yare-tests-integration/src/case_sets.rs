@@ -0,0 +1,18 @@
+use yare::parameterized;
+
+yare::cases! {
+    pub SMALL = {
+        case1 = { 0, 1 },
+        case2 = { 1, 2 },
+    }
+}
+
+#[parameterized(use SMALL)]
+fn test(some: u32, more: u32) {
+    assert!(some < more);
+}
+
+#[parameterized(use crate::case_sets::SMALL, case3 = { 2, 3 })]
+fn mixed(some: u32, more: u32) {
+    assert_eq!(some + 1, more);
+}
@@ -0,0 +1,22 @@
+use yare::parameterized;
+
+#[parameterized(compile_fail,
+    mismatched_types = {
+        r#"
+fn main() {
+    let _: u8 = "yare";
+}
+"#,
+        r#"
+error[E0308]: mismatched types
+ --> $DIR/mismatched_types.rs:3:17
+  |
+3 |     let _: u8 = "yare";
+  |            --   ^^^^^^ expected `u8`, found `&str`
+  |            |
+  |            expected due to this
+"#,
+    },
+    from_files = { "compile_fail/too_many_arguments.rs", "compile_fail/too_many_arguments.stderr" },
+)]
+fn fails_to_compile(source: &str, expected: &str) {}
@@ -0,0 +1,6 @@
+use yare::parameterized;
+
+#[parameterized(file = "data/temperatures.toml")]
+fn test(celsius: f64, fahrenheit: f64) {
+    assert_eq!(celsius * 9.0 / 5.0 + 32.0, fahrenheit);
+}
@@ -0,0 +1,18 @@
+use yare::parameterized;
+
+#[parameterized(files = "fixtures/*.input")]
+fn test(input: &str, expected: &str) {
+    let (left, operator, right) = match input.split(' ').collect::<Vec<_>>()[..] {
+        [left, operator, right] => (left, operator, right),
+        _ => panic!("invalid expression: {}", input),
+    };
+
+    let (left, right) = (left.parse::<u32>().unwrap(), right.parse::<u32>().unwrap());
+    let result = match operator {
+        "+" => left + right,
+        "*" => left * right,
+        _ => panic!("unknown operator: {}", operator),
+    };
+
+    assert_eq!(result.to_string(), expected);
+}
@@ -1,14 +1,27 @@
+mod case_attributes;
+mod case_info;
+mod case_sets;
+#[cfg(test)]
+mod compile_fail;
+mod data_file;
+mod file_glob;
 mod multiple_parameterized_tests;
 mod one_case;
 mod one_param;
+mod parameterized_mod;
+#[cfg(test)]
+mod property;
 mod qualifier_abi;
 mod qualifier_async;
 mod qualifier_async_complex;
 mod qualifier_const;
 mod return_type;
 mod should_panic;
+mod stacked_attributes;
 mod trailing_commas_between_arguments;
 mod trailing_commas_between_cases;
 mod two_cases;
 mod two_params;
+#[cfg(test)]
+mod variants;
 mod zero_params;
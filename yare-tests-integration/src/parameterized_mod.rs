@@ -0,0 +1,37 @@
+use yare::parameterized;
+
+#[parameterized(
+    case1 = { 0, 1 },
+    case2 = { 2, 3 },
+)]
+mod test {
+    #[test]
+    fn first(some: u32, more: u32) {
+        assert_eq!(some + 1, more);
+    }
+
+    #[test]
+    fn second(some: u32, more: u32) {
+        assert!(some < more);
+    }
+}
+
+#[parameterized(
+    case1 = { 0, 1 },
+)]
+mod with_test_macro {
+    #[tokio::test]
+    async fn first(some: u32, more: u32) {
+        assert_eq!(some + 1, more);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn second(some: u32, more: u32) {
+        assert!(some < more);
+    }
+
+    #[test]
+    fn third(some: u32, more: u32) {
+        assert_ne!(some, more);
+    }
+}
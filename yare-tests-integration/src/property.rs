@@ -0,0 +1,69 @@
+use yare::parameterized;
+use yare::proptest::prelude::*;
+
+#[parameterized(
+    zero = { 0, 0 },
+    commutes = { 3, 5 },
+    generated = strategy!(any::<u16>(), any::<u16>()),
+)]
+fn addition_commutes(a: u16, b: u16) {
+    assert_eq!(u32::from(a) + u32::from(b), u32::from(b) + u32::from(a));
+}
+
+#[parameterized(
+    empty = { String::new(), 0 },
+    // As printed for a minimal failing input
+    pasted = { "aaa".into(), 2 },
+    generated = strategy!(count in 0..8usize, word in "[a-z]{0,8}"),
+)]
+fn repeat_length(word: String, count: usize) {
+    assert_eq!(word.repeat(count).len(), word.len() * count);
+}
+
+#[parameterized(
+    generated = strategy!(any::<i8>()),
+)]
+fn with_result(n: i8) -> Result<(), String> {
+    let doubled = i16::from(n) * 2;
+
+    if doubled % 2 == 0 {
+        Ok(())
+    } else {
+        Err(format!("{} is odd", doubled))
+    }
+}
+
+#[parameterized(
+    generated = strategy!(0..100u8),
+)]
+fn describes_strategies(_n: u8) {
+    let case = yare::case!();
+
+    assert_eq!(case.args.len(), 1);
+    assert_eq!(case.args[0].0, "_n");
+    assert!(case.args[0].1.contains("100u8"));
+}
+
+#[parameterized(
+    generated = strategy!(10..100u8),
+)]
+#[should_panic(expected = "generated_minimal = { 10 },")]
+fn shrinks_failing_input(n: u8) {
+    assert!(n < 10);
+}
+
+#[parameterized(
+    generated = strategy!("[a-c]{3,6}"),
+)]
+#[should_panic(expected = r#"generated_minimal = { "aaa".into() },"#)]
+fn shrinks_failing_string(word: String) {
+    assert!(word.len() < 3);
+}
+
+#[parameterized(
+    generated = strategy!(prop::collection::vec("[a-c]", 2..4)),
+)]
+#[should_panic(expected = r#"generated_minimal = { ::std::vec!["a".into(), "a".into()] },"#)]
+fn shrinks_failing_vec(words: Vec<String>) {
+    assert!(words.len() < 2);
+}
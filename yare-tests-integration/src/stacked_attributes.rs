@@ -0,0 +1,41 @@
+use yare::parameterized;
+
+yare::cases! {
+    PAUSED = {
+        paused_wait = { 500, 500 },
+    }
+}
+
+#[parameterized(
+    no_wait = { 0, 0 },
+)]
+#[test_macro(tokio::test)]
+#[parameterized(use PAUSED)]
+#[test_macro(tokio::test(start_paused = true))]
+async fn test(wait: u64, at_least: u128) {
+    let start = tokio::time::Instant::now();
+    tokio::time::sleep(tokio::time::Duration::from_millis(wait)).await;
+
+    assert!(start.elapsed().as_millis() >= at_least);
+}
+
+#[parameterized(
+    case1 = { 1 },
+)]
+#[parameterized(
+    case2 = { 2 },
+)]
+mod module {
+    #[test]
+    #[parameterized(
+        case3 = { 3 },
+    )]
+    fn positive(value: u32) {
+        assert!(value > 0);
+    }
+
+    #[test]
+    fn small(value: u32) {
+        assert!(value < 3);
+    }
+}
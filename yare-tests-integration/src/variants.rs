@@ -0,0 +1,34 @@
+use yare::parameterized;
+
+pub mod shapes {
+    #[derive(Debug, yare::Variants)]
+    pub enum Shape {
+        Circle,
+        Square,
+        Triangle,
+    }
+
+    impl Shape {
+        pub fn corners(&self) -> u32 {
+            match self {
+                Shape::Circle => 0,
+                Shape::Square => 4,
+                Shape::Triangle => 3,
+            }
+        }
+    }
+}
+
+#[parameterized(variants = shapes::Shape)]
+fn from_another_module(shape: shapes::Shape) {
+    assert!(shape.corners() <= 4);
+}
+
+#[parameterized(variants = shapes::Shape {
+    Circle => 0,
+    Square => 4,
+    Triangle => 3,
+})]
+fn mapping(shape: shapes::Shape, corners: u32) {
+    assert_eq!(shape.corners(), corners);
+}
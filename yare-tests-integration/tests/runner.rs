@@ -0,0 +1,77 @@
+use yare::parameterized;
+use yare::runner::Runner;
+
+#[parameterized(
+    runner,
+    zero = { 0, 0 },
+    one = { 1, 1 },
+    two = { 2, 4 },
+)]
+fn square(value: u32, expected: u32) {
+    assert_eq!(value * value, expected);
+}
+
+#[parameterized(
+    runner,
+    empty = { "" },
+    ascii = { "yare" },
+)]
+fn parses(input: &str) -> Result<(), std::num::ParseIntError> {
+    input.len().to_string().parse::<u8>().map(|_| ())
+}
+
+#[parameterized(
+    runner,
+    out_of_bounds = { 3 },
+)]
+#[should_panic(expected = "out of bounds")]
+fn indexes(index: usize) {
+    vec![1, 2, 3].get(index).expect("out of bounds");
+}
+
+#[parameterized(
+    runner,
+    slow = { 1_000 },
+)]
+#[ignore]
+fn ignored(iterations: u32) {
+    assert!((0..iterations).sum::<u32>() > 0);
+}
+
+// Prints while the runner reports the results of other tests
+#[parameterized(
+    runner,
+    first = { 1 },
+    second = { 2 },
+)]
+fn prints(value: u32) {
+    println!("value: {}", value);
+}
+
+mod nested {
+    use yare::parameterized;
+
+    #[parameterized(
+        runner,
+        case = { 1 },
+    )]
+    pub fn test(value: u32) {
+        assert_eq!(yare::case!().name, "case");
+        assert_eq!(value, 1);
+    }
+}
+
+fn main() {
+    // Cases which are only known at runtime
+    let rows = (1..=3).map(|n| (format!("row_{}", n), n));
+
+    Runner::from_args()
+        .trials(square::trials())
+        .trials(parses::trials())
+        .trials(indexes::trials())
+        .trials(ignored::trials())
+        .trials(prints::trials())
+        .trials(nested::test::trials())
+        .cases("dynamic", rows, |n: u32| assert!(n > 0))
+        .run();
+}
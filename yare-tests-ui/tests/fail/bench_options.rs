@@ -0,0 +1,17 @@
+use yare::{parameterized, parameterized_bench};
+
+#[parameterized(bench, runner, one = { 1 })]
+fn with_runner(value: u32) {}
+
+#[parameterized_bench(one = { 1 })]
+async fn asynchronous(value: u32) {}
+
+#[parameterized_bench(one = { 1 })]
+#[should_panic]
+fn panics(value: u32) {}
+
+#[parameterized_bench(one = { 1 })]
+#[test_macro(tokio::test)]
+fn test_macro(value: u32) {}
+
+fn main() {}
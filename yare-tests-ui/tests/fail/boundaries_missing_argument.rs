@@ -0,0 +1,6 @@
+use yare::parameterized;
+
+#[parameterized(boundaries(n, radiks = 10))]
+fn test(n: u32, radix: u32) {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use yare::parameterized;
+
+#[parameterized(boundaries(text))]
+fn test(text: &str) {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use yare::parameterized;
+
+#[parameterized]
+#[case(one_plus_five, 1)]
+#[case::two_plus_five(2, 7, 9)]
+fn add5(input: u32, expected: u32) {}
+
+fn main() {}
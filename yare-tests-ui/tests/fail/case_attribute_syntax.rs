@@ -0,0 +1,8 @@
+use yare::parameterized;
+
+#[parameterized]
+#[case::two_plus_five[2, 7]]
+#[case]
+fn add5(input: u32, expected: u32) {}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use yare::parameterized;
+
+#[parameterized(
+    not_exhaustive = { cfg_select { unix => "/", windows => "\\" } },
+    unreachable = { cfg_select { _ => "/", unix => "\\" } },
+    empty = { cfg_select {} },
+)]
+fn test(separator: &str) {}
+
+#[parameterized(
+    rest = { 1, cfg_select { unix => 2, _ => 3 } },
+)]
+fn with_rest(#[rest] numbers: Vec<u32>) {}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use yare::parameterized;
+
+#[parameterized(compile_fail, one = { "fn main() {}" })]
+fn one_parameter(source: &str) {}
+
+#[parameterized(compile_fail, body = { "fn main() {}", "" })]
+fn with_body(source: &str, expected: &str) -> Result<(), ()> {
+    Ok(())
+}
+
+fn main() {}
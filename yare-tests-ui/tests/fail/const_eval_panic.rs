@@ -0,0 +1,11 @@
+use yare::parameterized;
+
+#[parameterized(const_eval, runner,
+    fine = { 2, 4 },
+    wrong = { 2, 5 },
+)]
+const fn double(value: u32, doubled: u32) {
+    assert!(value * 2 == doubled);
+}
+
+fn main() {}
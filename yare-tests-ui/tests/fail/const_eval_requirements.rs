@@ -0,0 +1,19 @@
+use yare::parameterized;
+
+#[parameterized(const_eval, one = { 1 })]
+fn not_const(value: u32) {
+    assert!(value == 1);
+}
+
+#[parameterized(const_eval, one = { 1 })]
+const fn returns(value: u32) -> Result<(), ()> {
+    Ok(())
+}
+
+#[parameterized(const_eval, one = { 1 })]
+#[should_panic]
+const fn panics(value: u32) {
+    panic!();
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use yare::parameterized;
+
+#[parameterized(one = { "1" })]
+fn both(#[from_str] #[into] n: u32) {}
+
+#[parameterized(one = { "1" })]
+fn with_arguments(#[from_str(radix = 16)] n: u32) {}
+
+#[parameterized(one = { "1" })]
+fn with_rest(#[rest] #[into] numbers: Vec<u64>) {}
+
+fn main() {}
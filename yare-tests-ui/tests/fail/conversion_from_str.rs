@@ -0,0 +1,8 @@
+use yare::parameterized;
+
+struct NotParseable;
+
+#[parameterized(runner, one = { "1" })]
+fn test(#[from_str] value: NotParseable) {}
+
+fn main() {}
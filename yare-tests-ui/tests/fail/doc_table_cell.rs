@@ -0,0 +1,11 @@
+use yare::parameterized;
+
+/// | case   | a     | b |
+/// |--------|-------|---|
+/// | good   | 1     | 2 |
+/// | broken | 1 + + | 2 |
+/// | short  | 1     |
+#[parameterized(doc_table)]
+fn test(a: u32, b: u32) {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use yare::parameterized;
+
+/// | case | a | c |
+/// |------|---|---|
+/// | one  | 1 | 3 |
+#[parameterized(doc_table)]
+fn columns(a: u32, b: u32) {}
+
+/// No table here.
+#[parameterized(doc_table)]
+fn missing(a: u32) {}
+
+fn main() {}
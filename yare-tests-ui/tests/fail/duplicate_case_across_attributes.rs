@@ -0,0 +1,12 @@
+use yare::parameterized;
+
+#[parameterized(
+    case1 = { 0 },
+    case2 = { 1 },
+)]
+#[parameterized(
+    case2 = { 2 },
+)]
+fn test(some: u32) {}
+
+fn main() {}
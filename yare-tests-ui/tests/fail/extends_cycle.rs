@@ -0,0 +1,11 @@
+use yare::parameterized;
+
+#[parameterized(
+    a = b { n = 1 },
+    b = a { n = 2 },
+    c = c { n = 3 },
+    d = a { n = 4 },
+)]
+fn test(n: u32) {}
+
+fn main() {}
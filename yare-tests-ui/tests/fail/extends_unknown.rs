@@ -0,0 +1,11 @@
+use yare::parameterized;
+
+#[parameterized(
+    base = { "localhost", 8080 },
+    unknown_base = missing { port = 0 },
+    unknown_parameter = base { prot = 0 },
+    twice = extends(base) { port = 0, port = 1 },
+)]
+fn test(host: &str, port: u16) {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use yare::parameterized;
+
+#[parameterized(values(1, 2, 3))]
+fn test(a: u32, b: u32) {}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use yare::parameterized;
+
+const END: u32 = 10;
+
+#[parameterized(range(0..END))]
+fn not_a_literal(n: u32) {}
+
+#[parameterized(range(10..0))]
+fn empty(n: u32) {}
+
+#[parameterized(range(0..100_000))]
+fn too_large(n: u32) {}
+
+#[parameterized(range(0..=170141183460469231731687303715884105727))]
+fn overflows_end(n: i128) {}
+
+#[parameterized(range(-170141183460469231731687303715884105727..170141183460469231731687303715884105727))]
+fn overflows_length(n: i128) {}
+
+fn main() {}
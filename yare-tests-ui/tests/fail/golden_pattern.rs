@@ -0,0 +1,13 @@
+use yare::parameterized;
+
+#[parameterized(golden = "tests/golden/{fn}/{name}.txt", one = { 1 })]
+fn unknown_placeholder(value: u32) {
+    yare::golden!().assert_eq(&value.to_string());
+}
+
+#[parameterized(golden = "tests/golden/{fn}.txt", one = { 1 })]
+fn without_case(value: u32) {
+    yare::golden!().assert_eq(&value.to_string());
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use yare::parameterized;
+
+#[parameterized(
+    case1 { 0 },
+)]
+fn test(some: u32) {}
+
+fn main() {}
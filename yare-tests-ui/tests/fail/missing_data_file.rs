@@ -0,0 +1,6 @@
+use yare::parameterized;
+
+#[parameterized(file = "tests/data/does_not_exist.csv")]
+fn test(some: u32) {}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use yare::parameterized;
+
+#[parameterized(
+    case1 = { 0 },
+)]
+mod test {
+    const A: u32 = 0;
+
+    fn helper(value: u32) -> u32 {
+        value + A
+    }
+}
+
+fn main() {}
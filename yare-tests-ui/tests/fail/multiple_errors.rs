@@ -0,0 +1,11 @@
+use yare::parameterized;
+
+#[parameterized(
+    case1 = { 0, 1, 2 },
+    case2 = [ 0 ],
+    case3 = { 0 },
+    case4 = ( 0, 1 ),
+)]
+fn test(some: u32, more: u32) {}
+
+fn main() {}
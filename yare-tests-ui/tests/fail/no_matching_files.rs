@@ -0,0 +1,6 @@
+use yare::parameterized;
+
+#[parameterized(files = "tests/fixtures/**/*.does_not_exist")]
+fn test(input: &str) {}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use std::rc::Rc;
+use yare::parameterized_types;
+
+#[derive(Clone)]
+struct Config;
+
+#[parameterized_types(
+    requires = Send + Sync,
+    forbids = Clone,
+    config = Config,
+    shared = Rc<u8>,
+    text = String,
+)]
+fn thread_safe<T>() {}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use yare::parameterized_types;
+
+#[parameterized_types(requires = Send, forbids = 'static, text = String)]
+fn two_parameters<T, U>(value: u8) {}
+
+#[parameterized_types(text = String, text = &str)]
+fn duplicate<T>() {}
+
+#[parameterized_types(requires = Send)]
+fn no_types<T>() {}
+
+fn main() {}
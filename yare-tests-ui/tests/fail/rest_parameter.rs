@@ -0,0 +1,15 @@
+use yare::parameterized;
+
+#[parameterized(one = { 1, 2 })]
+fn not_last(#[rest] items: Vec<u32>, n: u32) {}
+
+#[parameterized(one = { 1, 2 })]
+fn unsupported_type(n: u32, #[rest] items: Option<u32>) {}
+
+#[parameterized(one = { 1, 2 })]
+fn with_arguments(n: u32, #[rest(all)] items: Vec<u32>) {}
+
+#[parameterized(missing = { 1 })]
+fn missing_argument(a: u32, b: u32, #[rest] items: Vec<u32>) {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use yare::parameterized;
+
+#[parameterized(
+    runner,
+    one = { 1 },
+)]
+async fn test(value: u32) {}
+
+fn main() {}
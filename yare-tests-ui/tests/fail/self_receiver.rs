@@ -0,0 +1,12 @@
+use yare::parameterized;
+
+struct Calculator;
+
+impl Calculator {
+    #[parameterized(
+        case1 = { 0 },
+    )]
+    fn test(&self, some: u32) {}
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use yare::parameterized;
+
+const EXAMPLE: (&str, u32) = ("yare", 4);
+
+#[parameterized(runner, too_few_elements = { ..EXAMPLE })]
+fn test(text: &str, length: u32, ascii: bool) {}
+
+fn main() {}
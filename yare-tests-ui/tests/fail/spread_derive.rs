@@ -0,0 +1,9 @@
+#[derive(yare::Spread)]
+enum Shape {
+    Circle,
+}
+
+#[derive(yare::Spread)]
+struct Empty;
+
+fn main() {}
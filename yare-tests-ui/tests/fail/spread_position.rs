@@ -0,0 +1,11 @@
+use yare::parameterized;
+
+const PAIR: (u32, u32) = (1, 2);
+
+#[parameterized(
+    two = { ..PAIR, ..PAIR },
+    nothing_left = { 1, 2, 3, ..PAIR },
+)]
+fn spreads(a: u32, b: u32, c: u32) {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use yare::parameterized;
+
+#[parameterized(
+    generated = strategy!(0..10u32),
+)]
+async fn test(value: u32) {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use yare::parameterized;
+
+#[parameterized(
+    generated = strategy!(a in 0..10u32, c in 0..10u32),
+)]
+fn test(a: u32, b: u32) {}
+
+fn main() {}
@@ -1,7 +1,5 @@
 use yare::parameterized;
 
-// It actually does fail to compile, but idk why not for trybuild.
-
 #[parameterized(
     case1 = { 0, 1, },
     case1 = { 0, 1, },
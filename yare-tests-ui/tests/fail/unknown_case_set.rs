@@ -0,0 +1,9 @@
+use yare::parameterized;
+
+#[parameterized(
+    case1 = { 0 },
+    use NOT_DEFINED,
+)]
+fn test(some: u32) {}
+
+fn main() {}
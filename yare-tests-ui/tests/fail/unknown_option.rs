@@ -0,0 +1,9 @@
+use yare::parameterized;
+
+#[parameterized(
+    runer,
+    one = { 1 },
+)]
+fn test(value: u32) {}
+
+fn main() {}
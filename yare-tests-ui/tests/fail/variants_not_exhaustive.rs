@@ -0,0 +1,16 @@
+use yare::parameterized;
+
+enum Fruit {
+    Apple,
+    Pear,
+    Lemon,
+}
+
+// The `runner` option removes `#[cfg(test)]` from the generated module, so the check is compiled
+#[parameterized(runner, variants = Fruit {
+    Apple => "apple",
+    Pear => "pear",
+})]
+fn name(_fruit: Fruit, _name: &str) {}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(yare::Variants)]
+enum Fruit {
+    Apple,
+    Bramble(u32),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use yare::parameterized;
+
+// A test case may still be named `file`
+#[parameterized(
+    file = { 0 },
+)]
+fn test(some: u32) {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use yare::parameterized;
+
+yare::cases! {
+    CASES = {
+        case1 = { 0 },
+        case2 = { 1 },
+    }
+}
+
+#[parameterized(use CASES)]
+fn test(some: u32) {}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use yare::parameterized;
+
+#[parameterized(
+    case1 = { 0, 1 },
+    case2 = { 2, 3 },
+)]
+mod test {
+    #[test]
+    fn first(some: u32, more: u32) {
+        assert_eq!(some + 1, more);
+    }
+
+    #[test]
+    fn second(some: u32, more: u32) {
+        assert!(some < more);
+    }
+}
+
+fn main() {}
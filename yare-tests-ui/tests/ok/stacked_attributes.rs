@@ -0,0 +1,11 @@
+use yare::parameterized;
+
+#[parameterized(
+    case1 = { 0 },
+)]
+#[parameterized(
+    case2 = { 1 },
+)]
+fn test(some: u32) {}
+
+fn main() {}